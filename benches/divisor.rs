@@ -0,0 +1,61 @@
+//! Compares dividing by a precomputed [`Divisor`] against `/` and `%` on the inner value.
+//!
+//! Run with `cargo bench --bench divisor`.
+
+use beetle_nonzero::{nonzero, Divisor, NonZero};
+use std::{hint::black_box, time::Instant};
+
+const ITERATIONS: u32 = 10_000_000;
+
+/// Times summing `dividend $op $divisor` over every dividend
+macro_rules! time {
+    ($type: ty, $op: tt, $divisor: expr) => {{
+        let start = Instant::now();
+        let mut sum: $type = 0;
+        for dividend in 0..ITERATIONS {
+            sum = sum.wrapping_add(black_box(dividend as $type) $op $divisor);
+        }
+        black_box(sum);
+        start.elapsed()
+    }};
+}
+
+macro_rules! bench_division {
+    ($($type: ty => $divisor: expr),+) => {
+        $(
+            let divisor: NonZero<$type> = $divisor;
+            // Both divisors go through `black_box`, so the compiler cannot precompute its own magic
+            let magic = black_box(Divisor::from(divisor));
+            let plain = black_box(*divisor.get());
+
+            println!(
+                "{:>5}: `/` {:>10.2?}, `Divisor` {:>10.2?} | `%` {:>10.2?}, `Divisor` {:>10.2?}",
+                stringify!($type),
+                time!($type, /, plain),
+                time!($type, /, magic),
+                time!($type, %, plain),
+                time!($type, %, magic)
+            );
+        )+
+    };
+}
+
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_lossless
+)]
+fn main() {
+    bench_division!(
+        u8 => nonzero!(7u8),
+        u16 => nonzero!(7u16),
+        u32 => nonzero!(7u32),
+        u64 => nonzero!(7u64),
+        u128 => nonzero!(7u128),
+        i8 => nonzero!(-7i8),
+        i16 => nonzero!(-7i16),
+        i32 => nonzero!(-7i32),
+        i64 => nonzero!(-7i64),
+        i128 => nonzero!(-7i128)
+    );
+}
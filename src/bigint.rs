@@ -0,0 +1,99 @@
+//! Arbitrary-precision `NonZero<BigInt>` and `NonZero<BigUint>`.
+//!
+//! The operators here work on references, so big values are never cloned.
+
+use crate::{NonZero, ParseNonZeroError};
+use num_bigint::{BigInt, BigUint, ParseBigIntError};
+use std::ops::{Add, Div, Mul, Rem};
+
+/// Empty strings are rejected before parsing, so any other failure is an invalid digit
+impl From<ParseBigIntError> for ParseNonZeroError {
+    fn from(_: ParseBigIntError) -> Self {
+        Self::InvalidDigit
+    }
+}
+
+/// The sum of two positive values is positive
+impl<'b> Add<&'b NonZero<BigUint>> for &NonZero<BigUint> {
+    type Output = NonZero<BigUint>;
+
+    fn add(self, rhs: &'b NonZero<BigUint>) -> Self::Output {
+        // SAFETY: the sum of two nonzero unsigned values is nonzero
+        unsafe { NonZero::new_unchecked(self.get() + rhs.get()) }
+    }
+}
+
+/// Implements multiplication by reference, which can never overflow
+macro_rules! impl_mul_ref {
+    ($($type: ty),+) => {
+        $(
+            impl<'b> Mul<&'b NonZero<$type>> for &NonZero<$type> {
+                type Output = NonZero<$type>;
+
+                fn mul(self, rhs: &'b NonZero<$type>) -> Self::Output {
+                    // SAFETY: the product of two nonzero values is nonzero
+                    unsafe { NonZero::new_unchecked(self.get() * rhs.get()) }
+                }
+            }
+        )+
+    };
+}
+
+/// Implements division and remainder of a big integer by a nonzero big integer,
+/// both by value and by reference
+macro_rules! impl_div_big {
+    ($($type: ty),+) => {
+        $(
+            impl Div<NonZero<$type>> for $type {
+                type Output = Self;
+
+                fn div(self, rhs: NonZero<$type>) -> Self::Output {
+                    self / rhs.get()
+                }
+            }
+
+            impl<'b> Div<&'b NonZero<$type>> for &$type {
+                type Output = $type;
+
+                fn div(self, rhs: &'b NonZero<$type>) -> Self::Output {
+                    self / rhs.get()
+                }
+            }
+
+            impl Rem<NonZero<$type>> for $type {
+                type Output = Self;
+
+                fn rem(self, rhs: NonZero<$type>) -> Self::Output {
+                    self % rhs.get()
+                }
+            }
+
+            impl<'b> Rem<&'b NonZero<$type>> for &$type {
+                type Output = $type;
+
+                fn rem(self, rhs: &'b NonZero<$type>) -> Self::Output {
+                    self % rhs.get()
+                }
+            }
+        )+
+    };
+}
+
+/// Implements lossless conversions from primitive `NonZero<T>` into a nonzero big integer
+macro_rules! impl_from_primitive_big {
+    ($big: ty => $($primitive: ty),+) => {
+        $(
+            impl From<NonZero<$primitive>> for NonZero<$big> {
+                fn from(value: NonZero<$primitive>) -> Self {
+                    // SAFETY: the conversion preserves the nonzero value
+                    unsafe { Self::new_unchecked(<$big>::from(value.into_inner())) }
+                }
+            }
+        )+
+    };
+}
+
+impl_mul_ref!(BigUint, BigInt);
+impl_div_big!(BigUint, BigInt);
+impl_from_primitive_big!(BigUint => u8, u16, u32, u64, u128, usize);
+impl_from_primitive_big!(BigInt => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
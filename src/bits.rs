@@ -0,0 +1,79 @@
+//! Bit manipulation on primitive `NonZero<T>`, using the guarantee that at least one bit is set
+
+use crate::NonZero;
+
+macro_rules! impl_bits {
+    ($($type: ty),+) => {
+        $(
+            impl NonZero<$type> {
+                /// The number of leading zeros in the binary representation of the value,
+                /// which is always less than the bit width of the type
+                pub const fn leading_zeros(self) -> u32 {
+                    self.value.leading_zeros()
+                }
+
+                /// The number of trailing zeros in the binary representation of the value,
+                /// which is always less than the bit width of the type
+                pub const fn trailing_zeros(self) -> u32 {
+                    self.value.trailing_zeros()
+                }
+
+                /// The number of ones in the binary representation of the value, which is never zero
+                #[must_use]
+                pub const fn count_ones(self) -> NonZero<u32> {
+                    NonZero {
+                        value: self.value.count_ones(),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_bits_unsigned {
+    ($($type: ty),+) => {
+        $(
+            impl NonZero<$type> {
+                /// The base 2 logarithm of the value, rounded down.
+                /// This never panics, as the value is known to be nonzero.
+                pub const fn ilog2(self) -> u32 {
+                    self.value.ilog2()
+                }
+
+                /// The base 10 logarithm of the value, rounded down.
+                /// This never panics, as the value is known to be nonzero.
+                pub const fn ilog10(self) -> u32 {
+                    self.value.ilog10()
+                }
+
+                /// Whether exactly one bit of the value is set
+                pub const fn is_power_of_two(self) -> bool {
+                    self.value.is_power_of_two()
+                }
+
+                /// The smallest power of two greater than or equal to the value.
+                /// Returns `None` if it does not fit in the type.
+                pub const fn checked_next_power_of_two(self) -> Option<Self> {
+                    match self.value.checked_next_power_of_two() {
+                        Some(value) => Some(Self { value }),
+                        None => None,
+                    }
+                }
+
+                /// The number of bits needed to represent the value, which is never zero
+                #[must_use]
+                pub const fn bit_width(self) -> NonZero<u32> {
+                    // SAFETY: the value has fewer leading zeros than the type has bits
+                    NonZero {
+                        value: unsafe {
+                            std::num::NonZeroU32::new_unchecked(<$type>::BITS - self.leading_zeros())
+                        },
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_bits!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_bits_unsigned!(u8, u16, u32, u64, u128, usize);
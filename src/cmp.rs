@@ -0,0 +1,42 @@
+//! Comparing and borrowing `NonZero<T>` as its nonzero value
+
+use crate::{NonZero, Nonzeroable};
+use std::{borrow::Borrow, cmp::Ordering};
+
+impl<T> PartialEq<T> for NonZero<T>
+where
+    T: Nonzeroable + PartialEq,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.get() == other
+    }
+}
+
+impl<T> PartialOrd<T> for NonZero<T>
+where
+    T: Nonzeroable + PartialOrd,
+{
+    fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+        self.get().partial_cmp(other)
+    }
+}
+
+/// `NonZero<T>` hashes and compares the same way as `T`,
+/// so collections keyed by `NonZero<T>` can be queried with a plain `T`.
+impl<T> Borrow<T> for NonZero<T>
+where
+    T: Nonzeroable,
+{
+    fn borrow(&self) -> &T {
+        self.get()
+    }
+}
+
+impl<T> AsRef<T> for NonZero<T>
+where
+    T: Nonzeroable,
+{
+    fn as_ref(&self) -> &T {
+        self.get()
+    }
+}
@@ -0,0 +1,58 @@
+//! Conversions between `NonZero<T>` of different integer widths
+
+use crate::{NonZero, TryFromNonZeroError};
+
+/// Implements lossless `From` conversions from `NonZero<$from>` into each `NonZero<$to>`
+macro_rules! impl_widen {
+    ($from: ty => $($to: ty),+) => {
+        $(
+            impl From<NonZero<$from>> for NonZero<$to> {
+                fn from(value: NonZero<$from>) -> Self {
+                    // SAFETY: widening preserves the nonzero value
+                    unsafe { Self::new_unchecked(<$to>::from(value.into_inner())) }
+                }
+            }
+        )+
+    };
+}
+
+/// Implements fallible `TryFrom` conversions from `NonZero<$from>` into each `NonZero<$to>`
+macro_rules! impl_narrow {
+    ($from: ty => $($to: ty),+) => {
+        $(
+            impl TryFrom<NonZero<$from>> for NonZero<$to> {
+                type Error = TryFromNonZeroError<$from>;
+
+                fn try_from(value: NonZero<$from>) -> Result<Self, Self::Error> {
+                    match <$to>::try_from(value.into_inner()) {
+                        // SAFETY: a successful conversion preserves the nonzero value
+                        Ok(converted) => Ok(unsafe { Self::new_unchecked(converted) }),
+                        Err(_) => Err(TryFromNonZeroError::new(value)),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_widen!(u8 => u16, u32, u64, u128, usize, i16, i32, i64, i128, isize);
+impl_widen!(u16 => u32, u64, u128, usize, i32, i64, i128);
+impl_widen!(u32 => u64, u128, i64, i128);
+impl_widen!(u64 => u128, i128);
+impl_widen!(i8 => i16, i32, i64, i128, isize);
+impl_widen!(i16 => i32, i64, i128, isize);
+impl_widen!(i32 => i64, i128);
+impl_widen!(i64 => i128);
+
+impl_narrow!(u8 => i8);
+impl_narrow!(u16 => u8, i8, i16, isize);
+impl_narrow!(u32 => u8, u16, usize, i8, i16, i32, isize);
+impl_narrow!(u64 => u8, u16, u32, usize, i8, i16, i32, i64, isize);
+impl_narrow!(u128 => u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+impl_narrow!(usize => u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, isize);
+impl_narrow!(i8 => u8, u16, u32, u64, u128, usize);
+impl_narrow!(i16 => u8, u16, u32, u64, u128, usize, i8);
+impl_narrow!(i32 => u8, u16, u32, u64, u128, usize, i8, i16, isize);
+impl_narrow!(i64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+impl_narrow!(i128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+impl_narrow!(isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128);
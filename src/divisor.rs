@@ -0,0 +1,247 @@
+//! Division by a `NonZero<T>` divisor that is reused many times
+
+use crate::{wide::MulHi, NonZero, Nonzeroable};
+use std::{
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    ops::{Div, Rem},
+};
+
+/// A nonzero divisor with a precomputed magic multiplier and shifts,
+/// so that dividing by it takes a multiplication and shifts instead of a division.
+///
+/// Building a `Divisor` costs more than a single division,
+/// so it pays off when dividing by the same value many times.
+/// Recent CPUs divide narrow integers quickly in hardware,
+/// so whether it beats `/` depends on the width and the CPU, which `benches/divisor.rs` measures.
+///
+/// `new` is implemented separately for each integer type,
+/// so outside const contexts `Divisor::from` lets the type be inferred.
+pub struct Divisor<T: Nonzeroable> {
+    value: NonZero<T>,
+    /// The multiplier whose high product with the dividend approximates the quotient.
+    /// Unsigned divisors use the low `BITS` bits of `2^(BITS + ceil(log2(divisor))) / divisor` rounded up,
+    /// as its leading one does not fit.
+    /// Signed divisors use `2^(BITS + floor(log2(|divisor|))) / |divisor|` rounded up,
+    /// stored as the bits of the unsigned value, or zero when `|divisor|` is a power of two.
+    magic: T,
+    /// `0` when an unsigned divisor is one, and `1` for any other unsigned divisor.
+    /// Signed divisors always use `0`.
+    half_shift: u32,
+    /// The final shift of the quotient
+    shift: u32,
+}
+
+macro_rules! impl_divisor_unsigned {
+    ($($type: ty),+) => {
+        $(
+            impl Divisor<$type> {
+                /// Precomputes the magic multiplier and shifts for dividing by `divisor`
+                pub const fn new(divisor: NonZero<$type>) -> Self {
+                    let d = divisor.value.get();
+                    let log = if d == 1 { 0 } else { <$type>::BITS - (d - 1).leading_zeros() };
+                    // The magic is `floor(2^BITS * (2^log - d) / d) + 1`,
+                    // where the power of two wraps to zero when `log` is `BITS`
+                    let high = match (1 as $type).checked_shl(log) {
+                        Some(power) => power,
+                        None => 0,
+                    }
+                    .wrapping_sub(d);
+                    let half_shift = if log == 0 { 0 } else { 1 };
+                    Self {
+                        value: divisor,
+                        magic: Self::divide_wide(high, d).wrapping_add(1),
+                        half_shift,
+                        shift: log - half_shift,
+                    }
+                }
+
+                /// The divisor as a `NonZero`
+                pub const fn get(&self) -> NonZero<$type> {
+                    self.value
+                }
+
+                /// Divides `high * 2^BITS` by `divisor` with long division.
+                /// `high` must be below `divisor`, so the quotient fits in `BITS` bits.
+                const fn divide_wide(high: $type, divisor: $type) -> $type {
+                    let (mut remainder, mut quotient): ($type, $type) = (high, 0);
+                    let mut bit = 0;
+                    while bit < <$type>::BITS {
+                        let carry = remainder >> (<$type>::BITS - 1);
+                        remainder <<= 1;
+                        quotient <<= 1;
+                        if carry == 1 || remainder >= divisor {
+                            remainder = remainder.wrapping_sub(divisor);
+                            quotient |= 1;
+                        }
+                        bit += 1;
+                    }
+                    quotient
+                }
+
+                /// Divides by multiplying by the magic and shifting,
+                /// which rounds the quotient down like `/`
+                #[inline]
+                fn quotient(&self, dividend: $type) -> $type {
+                    let high = dividend.mul_hi(self.magic);
+                    (high + ((dividend - high) >> self.half_shift)) >> self.shift
+                }
+            }
+
+            impl Div<Divisor<$type>> for $type {
+                type Output = Self;
+
+                #[inline]
+                fn div(self, rhs: Divisor<$type>) -> Self::Output {
+                    rhs.quotient(self)
+                }
+            }
+
+            impl Rem<Divisor<$type>> for $type {
+                type Output = Self;
+
+                #[inline]
+                fn rem(self, rhs: Divisor<$type>) -> Self::Output {
+                    self - rhs.quotient(self) * rhs.value.value.get()
+                }
+            }
+
+            impl_divisor_traits!($type);
+        )+
+    };
+}
+
+macro_rules! impl_divisor_signed {
+    ($($type: ty => $unsigned: ty),+) => {
+        $(
+            impl Divisor<$type> {
+                /// Precomputes the magic multiplier and shift for dividing by `divisor`
+                pub const fn new(divisor: NonZero<$type>) -> Self {
+                    let d = divisor.unsigned_abs().value.get();
+                    let log = <$unsigned>::BITS - 1 - d.leading_zeros();
+                    // Powers of two only need the shift.
+                    // Otherwise `2^log` is below `d`, so the long division fits in `BITS` bits,
+                    // and the quotient is below `MAX`, so rounding it up cannot overflow.
+                    let magic = if d.is_power_of_two() {
+                        0
+                    } else {
+                        Divisor::<$unsigned>::divide_wide(1 << log, d) + 1
+                    };
+                    Self {
+                        value: divisor,
+                        magic: magic.cast_signed(),
+                        half_shift: 0,
+                        shift: log,
+                    }
+                }
+
+                /// The divisor as a `NonZero`
+                pub const fn get(&self) -> NonZero<$type> {
+                    self.value
+                }
+
+                /// Divides by multiplying by the magic and shifting,
+                /// which truncates the quotient towards zero like `/`.
+                /// `MIN / -1` wraps to `MIN`.
+                #[inline]
+                fn quotient(&self, dividend: $type) -> $type {
+                    // A nonzero magic reads as negative, so adding the dividend back
+                    // gives the high product with the magic as an unsigned value.
+                    // A zero magic leaves the dividend for the shift alone.
+                    let quotient = dividend.mul_hi(self.magic).wrapping_add(dividend);
+                    // Shifting rounds down, so negative quotients are first moved towards zero,
+                    // by `2^shift - 1` for powers of two and by `2^shift` otherwise
+                    let rounding = ((1 as $unsigned) << self.shift)
+                        .wrapping_sub(<$unsigned>::from(self.magic == 0));
+                    let sign = quotient >> (<$type>::BITS - 1);
+                    let quotient = quotient.wrapping_add((sign.cast_unsigned() & rounding).cast_signed())
+                        >> self.shift;
+                    // Negate the quotient of the absolute value when the divisor is negative
+                    let divisor_sign = self.value.value.get() >> (<$type>::BITS - 1);
+                    (quotient ^ divisor_sign).wrapping_sub(divisor_sign)
+                }
+            }
+
+            /// Panics if `self` is `MIN` and the divisor is `-1`, as the quotient overflows
+            impl Div<Divisor<$type>> for $type {
+                type Output = Self;
+
+                #[inline]
+                fn div(self, rhs: Divisor<$type>) -> Self::Output {
+                    assert!(
+                        !(self == <$type>::MIN && rhs.value.value.get() == -1),
+                        "attempt to divide with overflow"
+                    );
+                    rhs.quotient(self)
+                }
+            }
+
+            /// Never panics, as the remainder of `MIN / -1` is `0`
+            impl Rem<Divisor<$type>> for $type {
+                type Output = Self;
+
+                #[inline]
+                fn rem(self, rhs: Divisor<$type>) -> Self::Output {
+                    // `MIN / -1` wraps to `MIN`, and `MIN * -1` wraps back to `MIN`, leaving `0`
+                    self.wrapping_sub(rhs.quotient(self).wrapping_mul(rhs.value.value.get()))
+                }
+            }
+
+            impl_divisor_traits!($type);
+        )+
+    };
+}
+
+/// Implements the standard traits for a `Divisor<T>` by comparing the divisor,
+/// which determines the magic and shifts
+macro_rules! impl_divisor_traits {
+    ($type: ty) => {
+        impl From<NonZero<$type>> for Divisor<$type> {
+            fn from(divisor: NonZero<$type>) -> Self {
+                Self::new(divisor)
+            }
+        }
+
+        // Deriving would bound `T` rather than the `NonZero<T>` divisor
+        #[allow(clippy::expl_impl_clone_on_copy)]
+        impl Clone for Divisor<$type> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl Copy for Divisor<$type> {}
+
+        impl PartialEq for Divisor<$type> {
+            fn eq(&self, other: &Self) -> bool {
+                self.value == other.value
+            }
+        }
+
+        impl Eq for Divisor<$type> {}
+
+        impl Hash for Divisor<$type> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.value.hash(state);
+            }
+        }
+
+        impl Debug for Divisor<$type> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct("Divisor")
+                    .field("value", &self.value)
+                    .finish_non_exhaustive()
+            }
+        }
+    };
+}
+
+impl_divisor_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_divisor_signed!(
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize
+);
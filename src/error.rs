@@ -0,0 +1,219 @@
+//! Error types returned by fallible `NonZero<T>` operations
+
+use crate::{NonZero, Nonzeroable};
+use std::{
+    error::Error,
+    fmt::{Debug, Display},
+    num::{IntErrorKind, ParseIntError},
+};
+
+/// The error returned when a `NonZero<T>` is converted into a type that cannot hold its value.
+/// The value that failed to convert is handed back.
+pub struct TryFromNonZeroError<T: Nonzeroable> {
+    value: NonZero<T>,
+}
+
+impl<T> TryFromNonZeroError<T>
+where
+    T: Nonzeroable,
+{
+    pub(crate) const fn new(value: NonZero<T>) -> Self {
+        Self { value }
+    }
+
+    /// The value that failed to convert
+    pub const fn value(&self) -> &NonZero<T> {
+        &self.value
+    }
+
+    /// Consumes the error, returning the value that failed to convert
+    pub fn into_value(self) -> NonZero<T> {
+        self.value
+    }
+}
+
+// Deriving these would bound `T` rather than the `NonZero<T>` being held
+impl<T> Debug for TryFromNonZeroError<T>
+where
+    T: Nonzeroable,
+    NonZero<T>: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TryFromNonZeroError")
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl<T> Clone for TryFromNonZeroError<T>
+where
+    T: Nonzeroable,
+    NonZero<T>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<T> Copy for TryFromNonZeroError<T>
+where
+    T: Nonzeroable,
+    NonZero<T>: Copy,
+{
+}
+
+impl<T> PartialEq for TryFromNonZeroError<T>
+where
+    T: Nonzeroable,
+    NonZero<T>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T> Eq for TryFromNonZeroError<T>
+where
+    T: Nonzeroable,
+    NonZero<T>: Eq,
+{
+}
+
+impl<T> Display for TryFromNonZeroError<T>
+where
+    T: Nonzeroable + Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} is out of range for the target integer type",
+            self.value
+        )
+    }
+}
+
+impl<T> Error for TryFromNonZeroError<T>
+where
+    T: Nonzeroable + Display,
+    NonZero<T>: Debug,
+{
+}
+
+/// The error returned when a zero value is given where a nonzero value was required.
+/// The rejected value is handed back.
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
+pub struct ZeroError<T> {
+    value: T,
+}
+
+impl<T> ZeroError<T> {
+    pub(crate) const fn new(value: T) -> Self {
+        Self { value }
+    }
+
+    /// The value that was rejected
+    pub const fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Consumes the error, returning the value that was rejected
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Display for ZeroError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expected a nonzero value")
+    }
+}
+
+impl<T> Error for ZeroError<T> where T: Debug {}
+
+/// The error returned when parsing a `NonZero<T>` from a string fails.
+/// Its variants mirror the kinds of std's `ParseIntError`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseNonZeroError {
+    /// The string was empty
+    Empty,
+    /// The string contained a character that is not a valid digit
+    InvalidDigit,
+    /// The number was too large to fit in the target type
+    PosOverflow,
+    /// The number was too small to fit in the target type
+    NegOverflow,
+    /// The number was zero
+    Zero,
+}
+
+impl From<ParseIntError> for ParseNonZeroError {
+    fn from(error: ParseIntError) -> Self {
+        match error.kind() {
+            IntErrorKind::Empty => Self::Empty,
+            IntErrorKind::PosOverflow => Self::PosOverflow,
+            IntErrorKind::NegOverflow => Self::NegOverflow,
+            IntErrorKind::Zero => Self::Zero,
+            _ => Self::InvalidDigit,
+        }
+    }
+}
+
+impl Display for ParseNonZeroError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            Self::Empty => "cannot parse integer from empty string",
+            Self::InvalidDigit => "invalid digit found in string",
+            Self::PosOverflow => "number too large to fit in target type",
+            Self::NegOverflow => "number too small to fit in target type",
+            Self::Zero => "expected a nonzero value",
+        };
+        write!(f, "{message}")
+    }
+}
+
+impl Error for ParseNonZeroError {}
+
+/// The error returned when a `NonZero<T>` does not have the sign required by
+/// [`Positive`](crate::Positive) or [`Negative`](crate::Negative).
+/// The value that failed to convert is handed back.
+pub struct SignError<T: Nonzeroable> {
+    value: NonZero<T>,
+}
+
+forward_traits!(SignError, value);
+
+impl<T> SignError<T>
+where
+    T: Nonzeroable,
+{
+    pub(crate) const fn new(value: NonZero<T>) -> Self {
+        Self { value }
+    }
+
+    /// The value that failed to convert
+    pub const fn value(&self) -> &NonZero<T> {
+        &self.value
+    }
+
+    /// Consumes the error, returning the value that failed to convert
+    pub fn into_value(self) -> NonZero<T> {
+        self.value
+    }
+}
+
+impl<T> Display for SignError<T>
+where
+    T: Nonzeroable + Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} does not have the required sign", self.value)
+    }
+}
+
+impl<T> Error for SignError<T>
+where
+    T: Nonzeroable + Display,
+    NonZero<T>: Debug,
+{
+}
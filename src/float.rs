@@ -0,0 +1,154 @@
+//! Nonzero, finite floating point numbers
+
+use crate::{NonZero, Nonzeroable};
+use num_traits::{float::TotalOrder, Float};
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display, LowerExp, UpperExp},
+    hash::{Hash, Hasher},
+    ops::{Div, Neg},
+};
+
+/// A floating point number that is known to be finite and not equal to `0.0` or `-0.0`.
+///
+/// As NaN is excluded, values have a total order, so this implements `Eq`, `Ord` and `Hash`.
+#[derive(Copy, Clone)]
+pub struct NonZeroFloat<F> {
+    value: F,
+}
+
+impl<F> NonZeroFloat<F>
+where
+    F: Float,
+{
+    /// Returns a new `NonZeroFloat<F>` if `value` is finite and nonzero
+    pub fn new(value: F) -> Option<Self> {
+        (value.is_finite() && !value.is_zero()).then_some(Self { value })
+    }
+
+    /// The nonzero, finite value
+    pub const fn get(self) -> F {
+        self.value
+    }
+
+    /// The reciprocal `1 / value`, which is never zero.
+    /// Returns `None` if the reciprocal is infinite, which happens for some subnormal values.
+    pub fn recip(self) -> Option<Self> {
+        Self::new(self.value.recip())
+    }
+
+    /// The absolute value, which is also finite and nonzero
+    #[must_use]
+    pub fn abs(self) -> Self {
+        Self {
+            value: self.value.abs(),
+        }
+    }
+
+    /// Whether the value is greater than zero
+    pub fn is_positive(self) -> bool {
+        self.value.is_sign_positive()
+    }
+
+    /// Whether the value is less than zero
+    pub fn is_negative(self) -> bool {
+        self.value.is_sign_negative()
+    }
+}
+
+/// Negating a finite nonzero value never produces zero or infinity
+impl<F> Neg for NonZeroFloat<F>
+where
+    F: Float,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self { value: -self.value }
+    }
+}
+
+impl<F> PartialEq for NonZeroFloat<F>
+where
+    F: Float,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<F> Eq for NonZeroFloat<F> where F: Float {}
+
+impl<F> PartialOrd for NonZeroFloat<F>
+where
+    F: Float + TotalOrder,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Without NaN or signed zeros, the total order agrees with the usual ordering of floats
+impl<F> Ord for NonZeroFloat<F>
+where
+    F: Float + TotalOrder,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.total_cmp(&other.value)
+    }
+}
+
+/// Without NaN or signed zeros, equal values have equal bits
+impl<F> Hash for NonZeroFloat<F>
+where
+    F: Float,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.integer_decode().hash(state);
+    }
+}
+
+impl<F> From<NonZeroFloat<F>> for NonZero<F>
+where
+    F: Float + Nonzeroable,
+{
+    fn from(value: NonZeroFloat<F>) -> Self {
+        // SAFETY: the value is known to be nonzero
+        unsafe { Self::new_unchecked(value.value) }
+    }
+}
+
+/// Forwards each formatting trait to the nonzero value
+macro_rules! impl_fmt {
+    ($($trait: ident),+) => {
+        $(
+            impl<F> $trait for NonZeroFloat<F>
+            where
+                F: $trait,
+            {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    $trait::fmt(&self.value, f)
+                }
+            }
+        )+
+    };
+}
+
+impl_fmt!(Debug, Display, LowerExp, UpperExp);
+
+macro_rules! impl_div_float {
+    ($($type: ty),+) => {
+        $(
+            /// Dividing by a nonzero, finite value never produces NaN from a finite dividend
+            impl Div<NonZeroFloat<$type>> for $type {
+                type Output = Self;
+
+                fn div(self, rhs: NonZeroFloat<$type>) -> Self::Output {
+                    self / rhs.value
+                }
+            }
+        )+
+    };
+}
+
+impl_div_float!(f32, f64);
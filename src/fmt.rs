@@ -0,0 +1,22 @@
+//! Formatting `NonZero<T>` the same way as its nonzero value
+
+use crate::{NonZero, Nonzeroable};
+use std::fmt::{Binary, Debug, Display, LowerExp, LowerHex, Octal, UpperExp, UpperHex};
+
+/// Forwards each formatting trait to the nonzero value
+macro_rules! impl_fmt {
+    ($($trait: ident),+) => {
+        $(
+            impl<T> $trait for NonZero<T>
+            where
+                T: Nonzeroable + $trait,
+            {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    $trait::fmt(self.get(), f)
+                }
+            }
+        )+
+    };
+}
+
+impl_fmt!(Debug, Display, LowerHex, UpperHex, Binary, Octal, LowerExp, UpperExp);
@@ -0,0 +1,146 @@
+//! The `NonZeroInteger` trait, abstracting over nonzero integers of every width
+
+use crate::NonZero;
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, PrimInt};
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+};
+
+/// A nonzero integer of any width, signed or unsigned.
+///
+/// This is implemented by `NonZero<T>` for every primitive integer `T`
+/// and by the std `NonZero` types, so generic code can be written once for all of them.
+pub trait NonZeroInteger: Copy + Eq + Ord + Hash + Debug + Display {
+    /// The primitive integer holding the value
+    type Primitive: PrimInt;
+
+    /// The std `NonZero` type of the same width and signedness
+    type Std: NonZeroInteger<Primitive = Self::Primitive>;
+
+    /// The smallest nonzero value, which is `1` for unsigned integers
+    const MIN: Self;
+
+    /// The largest nonzero value
+    const MAX: Self;
+
+    /// The width of the integer in bits
+    const BITS: u32;
+
+    /// Returns a new nonzero integer if `value` is nonzero
+    fn new(value: Self::Primitive) -> Option<Self>;
+
+    /// The nonzero value as its primitive integer.
+    /// Named apart from `get`, so importing the trait leaves [`NonZero::get`] returning a reference.
+    fn to_primitive(self) -> Self::Primitive;
+
+    /// Converts into the std `NonZero` type of the same width and signedness
+    fn to_std(self) -> Self::Std;
+
+    /// Converts from the std `NonZero` type of the same width and signedness
+    fn from_std(value: Self::Std) -> Self;
+
+    /// Adds `other` to the value.
+    /// Returns `None` if the addition overflowed or the sum was zero.
+    fn checked_add(self, other: Self::Primitive) -> Option<Self> {
+        self.to_primitive().checked_add(&other).and_then(Self::new)
+    }
+
+    /// Subtracts `other` from the value.
+    /// Returns `None` if the subtraction overflowed or the difference was zero.
+    fn checked_sub(self, other: Self::Primitive) -> Option<Self> {
+        self.to_primitive().checked_sub(&other).and_then(Self::new)
+    }
+
+    /// Multiplies two nonzero integers.
+    /// Returns `None` if the multiplication overflowed.
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        self.to_primitive()
+            .checked_mul(&other.to_primitive())
+            .and_then(Self::new)
+    }
+
+    /// Raises the value to the power of `exp`.
+    /// Returns `None` if the result overflowed.
+    fn checked_pow(self, exp: u32) -> Option<Self> {
+        usize::try_from(exp)
+            .ok()
+            .and_then(|exp| num_traits::checked_pow(self.to_primitive(), exp))
+            .and_then(Self::new)
+    }
+}
+
+macro_rules! impl_nonzero_integer {
+    ($($primitive: ty => $std: ty),+) => {
+        $(
+            impl NonZeroInteger for NonZero<$primitive> {
+                type Primitive = $primitive;
+                type Std = $std;
+
+                const MIN: Self = Self { value: <$std>::MIN };
+                const MAX: Self = Self { value: <$std>::MAX };
+                const BITS: u32 = <$std>::BITS;
+
+                fn new(value: $primitive) -> Option<Self> {
+                    Self::new(value)
+                }
+
+                fn to_primitive(self) -> $primitive {
+                    self.into_inner()
+                }
+
+                fn to_std(self) -> $std {
+                    self.value
+                }
+
+                fn from_std(value: $std) -> Self {
+                    Self { value }
+                }
+            }
+
+            impl NonZeroInteger for $std {
+                type Primitive = $primitive;
+                type Std = Self;
+
+                const MIN: Self = Self::MIN;
+                const MAX: Self = Self::MAX;
+                const BITS: u32 = Self::BITS;
+
+                fn new(value: $primitive) -> Option<Self> {
+                    Self::new(value)
+                }
+
+                fn to_primitive(self) -> $primitive {
+                    self.get()
+                }
+
+                fn to_std(self) -> Self {
+                    self
+                }
+
+                fn from_std(value: Self) -> Self {
+                    value
+                }
+            }
+        )+
+    };
+}
+
+impl_nonzero_integer!(
+    u8 => NonZeroU8,
+    u16 => NonZeroU16,
+    u32 => NonZeroU32,
+    u64 => NonZeroU64,
+    u128 => NonZeroU128,
+    usize => NonZeroUsize,
+    i8 => NonZeroI8,
+    i16 => NonZeroI16,
+    i32 => NonZeroI32,
+    i64 => NonZeroI64,
+    i128 => NonZeroI128,
+    isize => NonZeroIsize
+);
@@ -0,0 +1,65 @@
+//! Sums and products over iterators of `NonZero<T>`
+
+use crate::{NonZero, Nonzeroable};
+use num_traits::{CheckedAdd, CheckedMul, One, Unsigned};
+use std::iter::Product;
+
+impl<T> NonZero<T>
+where
+    T: Nonzeroable,
+{
+    /// Multiplies every value of an iterator together.
+    /// The product of an empty iterator is `1`.
+    /// Returns `None` if the multiplication overflowed.
+    pub fn checked_product<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self>,
+        T: One + CheckedMul,
+    {
+        // SAFETY: one is nonzero
+        let one = unsafe { Self::new_unchecked(T::one()) };
+        iter.into_iter().try_fold(one, Self::checked_mul)
+    }
+
+    /// Adds every value of an iterator together.
+    /// Returns `None` if the iterator was empty, as the sum would be zero,
+    /// or if the addition overflowed.
+    pub fn checked_sum<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self>,
+        T: Unsigned + CheckedAdd,
+    {
+        let mut iter = iter.into_iter();
+        let first = iter.next()?;
+        iter.try_fold(first, |sum, value| sum.checked_add(value.into_inner()))
+    }
+}
+
+/// The product of an empty iterator is `1`.
+/// Panics if the multiplication overflows.
+impl<T> Product for NonZero<T>
+where
+    T: Nonzeroable + One + CheckedMul,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        Self::checked_product(iter).unwrap_or_else(|| panic!("attempt to multiply with overflow"))
+    }
+}
+
+/// The product of an empty iterator is `1`.
+/// Panics if the multiplication overflows.
+impl<'a, T> Product<&'a Self> for NonZero<T>
+where
+    T: Nonzeroable + One + CheckedMul,
+    Self: Clone,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.cloned().product()
+    }
+}
@@ -2,49 +2,131 @@
 
 use num_traits::Zero;
 use std::{
-    fmt::Display,
-    num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize},
+    mem::size_of,
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
     ops::Not,
 };
 
-/// An integer that is known to not equal zero.
-#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
-pub struct NonZero<T> {
-    value: T,
+#[macro_use]
+mod macros;
+
+#[cfg(feature = "num-bigint")]
+mod bigint;
+mod bits;
+mod cmp;
+mod convert;
+mod divisor;
+mod error;
+mod float;
+mod fmt;
+mod integer;
+mod iter;
+mod modular;
+mod num;
+mod number_theory;
+mod ops;
+mod parse;
+mod power_of_two;
+mod rational;
+#[cfg(feature = "serde")]
+mod serde;
+mod sign;
+mod wide;
+
+pub use divisor::Divisor;
+pub use error::{ParseNonZeroError, SignError, TryFromNonZeroError, ZeroError};
+pub use float::NonZeroFloat;
+pub use integer::NonZeroInteger;
+#[doc(hidden)]
+pub use macros::__private;
+pub use modular::{ModInt, ModularInteger, Modulus};
+pub use ops::DivNonZero;
+pub use power_of_two::PowerOfTwo;
+pub use rational::{Rational, RationalInteger};
+pub use sign::{Negative, Positive};
+
+/// A type that can be held by a [`NonZero`].
+///
+/// Primitive integers are stored as their std `NonZero` counterpart,
+/// so `Option<NonZero<T>>` is the same size as `T`.
+/// Other types can store themselves by setting `Repr = Self`.
+pub trait Nonzeroable: Zero {
+    /// How a nonzero `Self` is stored inside a [`NonZero`]
+    type Repr;
+
+    /// Converts a nonzero value into its representation
+    /// # Safety
+    /// `value` must be nonzero
+    unsafe fn to_repr(value: Self) -> Self::Repr;
+
+    /// Converts a representation back into the value it holds
+    fn from_repr(repr: Self::Repr) -> Self;
+
+    /// A reference to the value held by a representation
+    fn repr_ref(repr: &Self::Repr) -> &Self;
+
+    /// A mutable reference to the value held by a representation
+    /// # Safety
+    /// The caller must guarantee that the value is nonzero when the mutable reference is dropped
+    unsafe fn repr_mut(repr: &mut Self::Repr) -> &mut Self;
 }
 
-impl<T> Display for NonZero<T>
-where
-    T: Display,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.value)
-    }
+/// An integer that is known to not equal zero.
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct NonZero<T: Nonzeroable> {
+    value: T::Repr,
 }
 
 impl<T> NonZero<T>
 where
-    T: Zero,
+    T: Nonzeroable,
 {
     /// Returns a new `NonZero<T>` if `value` is nonzero
     pub fn new(value: T) -> Option<Self> {
-        value.is_zero().not().then_some(Self { value })
+        value
+            .is_zero()
+            .not()
+            .then(|| unsafe { Self::new_unchecked(value) })
+    }
+
+    /// Returns a new `NonZero<T>` if `value` is nonzero
+    /// # Errors
+    /// Hands `value` back in a [`ZeroError`] if it was zero
+    pub fn try_new(value: T) -> Result<Self, ZeroError<T>> {
+        if value.is_zero() {
+            Err(ZeroError::new(value))
+        } else {
+            Ok(unsafe { Self::new_unchecked(value) })
+        }
     }
 
     /// Returns a new `NonZero` without checking that the provided value is nonzero.
     /// # Safety
     /// `value` must be known to be nonzero
-    pub const unsafe fn new_unchecked(value: T) -> Self {
-        Self { value }
+    pub unsafe fn new_unchecked(value: T) -> Self {
+        Self {
+            value: T::to_repr(value),
+        }
     }
 
     /// Tries replacing the nonzero value with a new one.
     /// If the new value is nonzero this returns the old value,
     /// otherwise this returns None.
     pub fn replace(&mut self, new_value: T) -> Option<T> {
-        let mut other = Self::new(new_value)?;
+        self.try_replace(new_value).ok()
+    }
+
+    /// Tries replacing the nonzero value with a new one.
+    /// If the new value is nonzero this returns the old value.
+    /// # Errors
+    /// Hands the new value back in a [`ZeroError`] if it was zero
+    pub fn try_replace(&mut self, new_value: T) -> Result<T, ZeroError<T>> {
+        let mut other = Self::try_new(new_value)?;
         self.swap(&mut other);
-        Some(other.value)
+        Ok(other.into_inner())
     }
 
     /// Sets `self.value` using the provided value.
@@ -63,12 +145,19 @@ where
     /// # Safety
     /// `value` must be known to be nonzero
     pub unsafe fn set_unchecked(&mut self, value: T) {
-        self.value = value;
+        self.value = T::to_repr(value);
     }
 
     /// Applies a function to the inner value and returns a `NonZero` if the result was nonzero.
     pub fn map(self, f: impl Fn(T) -> T) -> Option<Self> {
-        Self::new(f(self.value))
+        Self::new(f(self.into_inner()))
+    }
+
+    /// Applies a function to the inner value and returns a `NonZero` if the result was nonzero.
+    /// # Errors
+    /// Hands the result back in a [`ZeroError`] if it was zero
+    pub fn try_map(self, f: impl Fn(T) -> T) -> Result<Self, ZeroError<T>> {
+        Self::try_new(f(self.into_inner()))
     }
 
     /// Applies a function to the inner value and returns a `NonZero` if the result was nonzero.
@@ -76,41 +165,148 @@ where
     /// `f` must return a nonzero integer
     #[must_use]
     pub unsafe fn map_unchecked(self, f: impl Fn(T) -> T) -> Self {
-        Self::new_unchecked(f(self.value))
+        Self::new_unchecked(f(self.into_inner()))
     }
 
     /// A reference to the nonzero value
-    pub const fn get(&self) -> &T {
-        &self.value
+    pub fn get(&self) -> &T {
+        T::repr_ref(&self.value)
+    }
+
+    /// Consumes the `NonZero`, returning the nonzero value
+    pub fn into_inner(self) -> T {
+        T::from_repr(self.value)
     }
 
     /// A mutable reference to the nonzero value
     /// # Safety
     /// The caller must guarantee that the value is nonzero when the mutable reference is dropped
     #[deprecated(since = "0.3.14", note = "use `swap` instead")]
-    pub const unsafe fn get_mut(&mut self) -> &mut T {
-        &mut self.value
+    pub unsafe fn get_mut(&mut self) -> &mut T {
+        T::repr_mut(&mut self.value)
     }
 
     /// Swap the nonzero value of two `NonZero`s
-    pub fn swap(&mut self, other: &mut Self) {
+    pub const fn swap(&mut self, other: &mut Self) {
         std::mem::swap(self, other);
     }
 }
 
-macro_rules! impl_from_primitive {
-    ($new_name: ty, $primitive: ty) => {
-        impl From<$primitive> for $new_name {
-            fn from(value: $primitive) -> Self {
-                Self { value: value.get() }
+macro_rules! impl_primitive {
+    ($primitive: ty, $std: ty, $new: ident, $get: ident) => {
+        impl NonZero<$primitive> {
+            #[doc = concat!(
+                "Returns a new `NonZero<", stringify!($primitive), ">` if `value` is nonzero.\n",
+                "Unlike [`NonZero::new`], this can be used in const contexts."
+            )]
+            pub const fn $new(value: $primitive) -> Option<Self> {
+                match <$std>::new(value) {
+                    Some(value) => Some(Self { value }),
+                    None => None,
+                }
+            }
+
+            /// The nonzero value, returned by value.
+            /// Unlike [`NonZero::get`], this can be used in const contexts.
+            pub const fn $get(self) -> $primitive {
+                self.value.get()
+            }
+        }
+
+        impl Nonzeroable for $primitive {
+            type Repr = $std;
+
+            unsafe fn to_repr(value: Self) -> Self::Repr {
+                <$std>::new_unchecked(value)
+            }
+
+            fn from_repr(repr: Self::Repr) -> Self {
+                repr.get()
+            }
+
+            fn repr_ref(repr: &Self::Repr) -> &Self {
+                // SAFETY: the std `NonZero` types have the same layout as their primitive
+                unsafe { &*std::ptr::from_ref(repr).cast::<Self>() }
+            }
+
+            unsafe fn repr_mut(repr: &mut Self::Repr) -> &mut Self {
+                &mut *std::ptr::from_mut(repr).cast::<Self>()
+            }
+        }
+
+        impl TryFrom<$primitive> for NonZero<$primitive> {
+            type Error = ZeroError<$primitive>;
+
+            fn try_from(value: $primitive) -> Result<Self, Self::Error> {
+                Self::try_new(value)
+            }
+        }
+
+        impl From<$std> for NonZero<$primitive> {
+            fn from(value: $std) -> Self {
+                Self { value }
+            }
+        }
+
+        impl From<NonZero<$primitive>> for $std {
+            fn from(value: NonZero<$primitive>) -> Self {
+                value.value
+            }
+        }
+
+        // `Option<NonZero<T>>` reuses the niche of the std `NonZero` representation
+        const _: () = assert!(size_of::<Option<NonZero<$primitive>>>() == size_of::<$primitive>());
+    };
+}
+
+impl_primitive!(u8, NonZeroU8, new_u8, get_u8);
+impl_primitive!(u16, NonZeroU16, new_u16, get_u16);
+impl_primitive!(u32, NonZeroU32, new_u32, get_u32);
+impl_primitive!(u64, NonZeroU64, new_u64, get_u64);
+impl_primitive!(u128, NonZeroU128, new_u128, get_u128);
+impl_primitive!(usize, NonZeroUsize, new_usize, get_usize);
+impl_primitive!(i8, NonZeroI8, new_i8, get_i8);
+impl_primitive!(i16, NonZeroI16, new_i16, get_i16);
+impl_primitive!(i32, NonZeroI32, new_i32, get_i32);
+impl_primitive!(i64, NonZeroI64, new_i64, get_i64);
+impl_primitive!(i128, NonZeroI128, new_i128, get_i128);
+impl_primitive!(isize, NonZeroIsize, new_isize, get_isize);
+
+macro_rules! impl_self_repr {
+    ($type: ty) => {
+        impl Nonzeroable for $type {
+            type Repr = Self;
+
+            unsafe fn to_repr(value: Self) -> Self::Repr {
+                value
+            }
+
+            fn from_repr(repr: Self::Repr) -> Self {
+                repr
+            }
+
+            fn repr_ref(repr: &Self::Repr) -> &Self {
+                repr
+            }
+
+            unsafe fn repr_mut(repr: &mut Self::Repr) -> &mut Self {
+                repr
+            }
+        }
+
+        impl TryFrom<$type> for NonZero<$type> {
+            type Error = ZeroError<$type>;
+
+            fn try_from(value: $type) -> Result<Self, Self::Error> {
+                Self::try_new(value)
             }
         }
     };
 }
 
-impl_from_primitive!(NonZero<u8>, NonZeroU8);
-impl_from_primitive!(NonZero<u16>, NonZeroU16);
-impl_from_primitive!(NonZero<u32>, NonZeroU32);
-impl_from_primitive!(NonZero<u64>, NonZeroU64);
-impl_from_primitive!(NonZero<u128>, NonZeroU128);
-impl_from_primitive!(NonZero<usize>, NonZeroUsize);
+impl_self_repr!(f32);
+impl_self_repr!(f64);
+#[cfg(feature = "num-bigint")]
+impl_self_repr!(num_bigint::BigInt);
+#[cfg(feature = "num-bigint")]
+impl_self_repr!(num_bigint::BigUint);
@@ -0,0 +1,128 @@
+//! The `nonzero!` macro for building `NonZero<T>` constants,
+//! and internal macros for types wrapping a `NonZero<T>`
+
+/// Implements the standard traits of a struct wrapping a `NonZero<T>` by forwarding to that field.
+/// Deriving them would bound `T` rather than the `NonZero<T>` being wrapped,
+/// which fails to compile since `NonZero<T>` is stored as `T::Repr`.
+macro_rules! forward_traits {
+    ($wrapper: ident, $field: ident) => {
+        impl<T> std::fmt::Debug for $wrapper<T>
+        where
+            T: $crate::Nonzeroable,
+            $crate::NonZero<T>: std::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct(stringify!($wrapper))
+                    .field(stringify!($field), &self.$field)
+                    .finish()
+            }
+        }
+
+        impl<T> Clone for $wrapper<T>
+        where
+            T: $crate::Nonzeroable,
+            $crate::NonZero<T>: Clone,
+        {
+            fn clone(&self) -> Self {
+                Self {
+                    $field: self.$field.clone(),
+                }
+            }
+        }
+
+        impl<T> Copy for $wrapper<T>
+        where
+            T: $crate::Nonzeroable,
+            $crate::NonZero<T>: Copy,
+        {
+        }
+
+        impl<T> PartialEq for $wrapper<T>
+        where
+            T: $crate::Nonzeroable,
+            $crate::NonZero<T>: PartialEq,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.$field == other.$field
+            }
+        }
+
+        impl<T> Eq for $wrapper<T>
+        where
+            T: $crate::Nonzeroable,
+            $crate::NonZero<T>: Eq,
+        {
+        }
+
+        impl<T> PartialOrd for $wrapper<T>
+        where
+            T: $crate::Nonzeroable,
+            $crate::NonZero<T>: PartialOrd,
+        {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                self.$field.partial_cmp(&other.$field)
+            }
+        }
+
+        impl<T> Ord for $wrapper<T>
+        where
+            T: $crate::Nonzeroable,
+            $crate::NonZero<T>: Ord,
+        {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.$field.cmp(&other.$field)
+            }
+        }
+
+        impl<T> std::hash::Hash for $wrapper<T>
+        where
+            T: $crate::Nonzeroable,
+            $crate::NonZero<T>: std::hash::Hash,
+        {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.$field.hash(state);
+            }
+        }
+    };
+}
+
+/// Builds a [`NonZero`](crate::NonZero) from a primitive integer at compile time.
+///
+/// The value is checked in a `const` block, so a zero literal or constant expression
+/// fails to compile, and the result can be used to initialize `const` and `static` items.
+#[macro_export]
+macro_rules! nonzero {
+    ($value: expr) => {
+        const {
+            let value = $value;
+            match ::core::num::NonZero::new(value) {
+                // SAFETY: the std `NonZero` is the representation of a primitive `NonZero<T>`
+                ::core::option::Option::Some(repr) => unsafe {
+                    $crate::__private::from_repr($crate::__private::marker(&value), repr)
+                },
+                ::core::option::Option::None => ::core::panic!("nonzero! requires a nonzero value"),
+            }
+        }
+    };
+}
+
+/// Items used by `nonzero!`, which are not part of the public API
+pub mod __private {
+    use crate::{NonZero, Nonzeroable};
+    use std::marker::PhantomData;
+
+    /// Captures the type of `value`, so the macro can infer `T` from its argument
+    pub const fn marker<T>(_value: &T) -> PhantomData<T> {
+        PhantomData
+    }
+
+    /// Builds a `NonZero<T>` directly from its representation
+    /// # Safety
+    /// `repr` must hold a nonzero value
+    pub const unsafe fn from_repr<T: Nonzeroable>(
+        _marker: PhantomData<T>,
+        repr: T::Repr,
+    ) -> NonZero<T> {
+        NonZero { value: repr }
+    }
+}
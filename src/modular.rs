@@ -0,0 +1,283 @@
+//! Modular arithmetic by a `NonZero<T>` modulus
+
+use crate::{
+    wide::{MulHi, Widen},
+    NonZero, Nonzeroable,
+};
+use num_traits::{Bounded, PrimInt, Unsigned};
+use std::{
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    ops::{Add, Mul, Sub},
+};
+
+/// The integers that a [`Modulus`] can be built from.
+///
+/// Reductions multiply in the unsigned type twice as wide,
+/// so this is implemented for `u8`, `u16`, `u32` and `u64`.
+pub trait ModularInteger: Nonzeroable<Repr: Copy + Eq> + PrimInt + Unsigned + Widen {
+    /// The inverse of `value` modulo `modulus`, where `value` is less than `modulus`.
+    /// Returns `None` if they have a common divisor other than `1`.
+    fn inverse_mod(value: NonZero<Self>, modulus: NonZero<Self>) -> Option<Self>;
+}
+
+macro_rules! impl_modular_integer {
+    ($($type: ty),+) => {
+        $(
+            impl ModularInteger for $type {
+                fn inverse_mod(value: NonZero<Self>, modulus: NonZero<Self>) -> Option<Self> {
+                    let (gcd, coefficient, _) = value.extended_gcd(modulus);
+                    // `value * coefficient` is `1` modulo `modulus`,
+                    // and the coefficient is smaller than `modulus` in magnitude
+                    (gcd.value.get() == 1).then(|| {
+                        if coefficient < 0 {
+                            modulus.value.get() - coefficient.unsigned_abs()
+                        } else {
+                            coefficient.cast_unsigned()
+                        }
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_modular_integer!(u8, u16, u32, u64);
+
+/// A nonzero modulus with a precomputed reciprocal,
+/// so that reducing by it takes a multiplication instead of a division.
+///
+/// Uses Barrett reduction, which unlike Montgomery reduction also works for even moduli.
+#[derive(Clone, Copy)]
+pub struct Modulus<T: ModularInteger> {
+    modulus: NonZero<T>,
+    /// `floor(Wide::MAX / modulus)`, an approximation of `2^(2 * BITS) / modulus`
+    reciprocal: T::Wide,
+}
+
+impl<T> Modulus<T>
+where
+    T: ModularInteger,
+{
+    /// Precomputes the reciprocal of `modulus`
+    pub fn new(modulus: NonZero<T>) -> Self {
+        Self {
+            modulus,
+            reciprocal: T::Wide::max_value() / modulus.into_inner().widen(),
+        }
+    }
+
+    /// The modulus as a `NonZero<T>`
+    pub const fn get(&self) -> NonZero<T> {
+        self.modulus
+    }
+
+    /// The remainder of dividing `value` by the modulus
+    pub fn reduce(&self, value: T) -> T {
+        self.reduce_wide(value.widen())
+    }
+
+    /// The remainder of dividing a double-width `value` by the modulus
+    fn reduce_wide(&self, value: T::Wide) -> T {
+        let modulus = self.modulus.into_inner().widen();
+        // The reciprocal is rounded down, so the quotient is at most one less than the true quotient
+        let quotient = value.mul_hi(self.reciprocal);
+        let mut remainder = value - quotient * modulus;
+        while remainder >= modulus {
+            remainder = remainder - modulus;
+        }
+        T::truncate(remainder)
+    }
+}
+
+/// The reciprocal is determined by the modulus, so only the modulus is compared
+impl<T> PartialEq for Modulus<T>
+where
+    T: ModularInteger,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.modulus == other.modulus
+    }
+}
+
+impl<T> Eq for Modulus<T> where T: ModularInteger {}
+
+impl<T> Hash for Modulus<T>
+where
+    T: ModularInteger + Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.modulus.get().hash(state);
+    }
+}
+
+impl<T> Debug for Modulus<T>
+where
+    T: ModularInteger + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Modulus")
+            .field("modulus", self.modulus.get())
+            .finish_non_exhaustive()
+    }
+}
+
+/// An integer modulo a [`Modulus`], which is always reduced to `0..modulus`.
+///
+/// Combining values with different moduli panics.
+#[derive(Clone, Copy)]
+pub struct ModInt<T: ModularInteger> {
+    value: T,
+    modulus: Modulus<T>,
+}
+
+impl<T> ModInt<T>
+where
+    T: ModularInteger,
+{
+    /// Returns `value` modulo `modulus`
+    pub fn new(value: T, modulus: Modulus<T>) -> Self {
+        Self {
+            value: modulus.reduce(value),
+            modulus,
+        }
+    }
+
+    /// The reduced value, which is less than the modulus
+    pub const fn get(self) -> T {
+        self.value
+    }
+
+    /// The modulus the value is reduced by
+    pub const fn modulus(self) -> Modulus<T> {
+        self.modulus
+    }
+
+    /// Raises the value to the power of `exp` by repeated squaring
+    #[must_use]
+    pub fn pow(self, mut exp: u32) -> Self {
+        let mut base = self;
+        let mut result = Self::new(T::one(), self.modulus);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// The value whose product with `self` is `1`.
+    /// Returns `None` if the value has a common divisor with the modulus other than `1`,
+    /// which includes zero unless the modulus is `1`.
+    pub fn inverse(self) -> Option<Self> {
+        if self.modulus.get().into_inner() == T::one() {
+            // Every value is `0` modulo `1`, and `0 * 0` is `0`, which is also `1`
+            return Some(self);
+        }
+        let value = T::inverse_mod(NonZero::new(self.value)?, self.modulus.get())?;
+        Some(Self { value, ..self })
+    }
+
+    /// Builds a value from a result already reduced by the same modulus
+    fn with_value(self, rhs: Self, value: T) -> Self {
+        assert!(
+            self.modulus == rhs.modulus,
+            "attempt to combine values with different moduli"
+        );
+        Self { value, ..self }
+    }
+}
+
+/// Panics if the moduli differ
+impl<T> Add for ModInt<T>
+where
+    T: ModularInteger,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        // Both values are below the modulus, so the sum is below twice the modulus
+        let sum = self.value.widen() + rhs.value.widen();
+        let modulus = self.modulus.get().into_inner().widen();
+        let sum = if sum >= modulus { sum - modulus } else { sum };
+        self.with_value(rhs, T::truncate(sum))
+    }
+}
+
+/// Panics if the moduli differ
+impl<T> Sub for ModInt<T>
+where
+    T: ModularInteger,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let difference = if self.value >= rhs.value {
+            self.value - rhs.value
+        } else {
+            // `rhs.value` is below the modulus, so this stays below the modulus
+            self.value + (self.modulus.get().into_inner() - rhs.value)
+        };
+        self.with_value(rhs, difference)
+    }
+}
+
+/// Panics if the moduli differ
+impl<T> Mul for ModInt<T>
+where
+    T: ModularInteger,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        // Both values are below the modulus, so the product fits in the wide type
+        let product = self
+            .modulus
+            .reduce_wide(self.value.widen() * rhs.value.widen());
+        self.with_value(rhs, product)
+    }
+}
+
+impl<T> PartialEq for ModInt<T>
+where
+    T: ModularInteger,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.modulus == other.modulus
+    }
+}
+
+impl<T> Eq for ModInt<T> where T: ModularInteger {}
+
+impl<T> Hash for ModInt<T>
+where
+    T: ModularInteger + Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+        self.modulus.hash(state);
+    }
+}
+
+impl<T> Debug for ModInt<T>
+where
+    T: ModularInteger + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ModInt")
+            .field("value", &self.value)
+            .field("modulus", self.modulus.get().get())
+            .finish()
+    }
+}
+
+impl<T> Display for ModInt<T>
+where
+    T: ModularInteger + Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.value, f)
+    }
+}
@@ -0,0 +1,126 @@
+//! `num_traits` implementations, so `NonZero<T>` fits into generic numeric code.
+//!
+//! Traits that require `Zero`, such as `Num` and `Signed`, cannot be implemented.
+//! The sign helpers of `Signed` are provided as inherent methods instead.
+
+use crate::{NonZero, NonZeroInteger, Nonzeroable};
+use num_traits::{
+    Bounded, CheckedAdd, CheckedMul, FromPrimitive, NumCast, One, Pow, ToPrimitive, Unsigned,
+};
+
+impl<T> One for NonZero<T>
+where
+    T: Nonzeroable + One + CheckedMul,
+{
+    fn one() -> Self {
+        // SAFETY: one is nonzero
+        unsafe { Self::new_unchecked(T::one()) }
+    }
+}
+
+/// The bounds of the nonzero values, so the minimum of an unsigned `NonZero<T>` is `1`
+impl<T> Bounded for NonZero<T>
+where
+    T: Nonzeroable,
+    Self: NonZeroInteger,
+{
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
+/// Panics if the result overflows
+impl<T> Pow<u32> for NonZero<T>
+where
+    T: Nonzeroable + Clone + One + CheckedMul,
+{
+    type Output = Self;
+
+    fn pow(self, rhs: u32) -> Self::Output {
+        self.checked_pow(rhs)
+            .unwrap_or_else(|| panic!("attempt to multiply with overflow"))
+    }
+}
+
+impl<T> CheckedMul for NonZero<T>
+where
+    T: Nonzeroable + CheckedMul,
+{
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        self.get().checked_mul(v.get()).and_then(Self::new)
+    }
+}
+
+impl<T> CheckedAdd for NonZero<T>
+where
+    T: Nonzeroable + Unsigned + CheckedAdd,
+{
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        self.get().checked_add(v.get()).and_then(Self::new)
+    }
+}
+
+impl<T> ToPrimitive for NonZero<T>
+where
+    T: Nonzeroable + ToPrimitive,
+{
+    fn to_i64(&self) -> Option<i64> {
+        self.get().to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.get().to_u64()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.get().to_i128()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        self.get().to_u128()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.get().to_f64()
+    }
+}
+
+/// Casting fails if the value does not fit in `T` or is zero
+impl<T> NumCast for NonZero<T>
+where
+    T: Nonzeroable + NumCast,
+{
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        <T as NumCast>::from(n).and_then(Self::new)
+    }
+}
+
+/// Conversion fails if the value does not fit in `T` or is zero
+impl<T> FromPrimitive for NonZero<T>
+where
+    T: Nonzeroable + FromPrimitive,
+{
+    fn from_i64(n: i64) -> Option<Self> {
+        T::from_i64(n).and_then(Self::new)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        T::from_u64(n).and_then(Self::new)
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        T::from_i128(n).and_then(Self::new)
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        T::from_u128(n).and_then(Self::new)
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        T::from_f64(n).and_then(Self::new)
+    }
+}
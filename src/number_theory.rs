@@ -0,0 +1,153 @@
+//! Number theory on primitive `NonZero<T>`.
+//!
+//! The greatest common divisor of nonzero values is nonzero,
+//! so results keep the `NonZero` guarantee instead of falling back to `T`.
+//! For signed integers the divisor is returned as the unsigned `NonZero` of the same width,
+//! as the divisor of `MIN` and `MIN` does not fit in the signed type.
+
+use crate::NonZero;
+use num_traits::{CheckedNeg, CheckedRem, PrimInt};
+
+/// The greatest common divisor of `a` and `b` by Euclid's algorithm, where `b` is nonzero.
+/// The result is positive, unless both values are multiples of `T::MIN`,
+/// in which case it is `T::MIN`, as the positive divisor does not fit in `T`.
+pub fn gcd<T>(mut a: T, mut b: T) -> T
+where
+    T: PrimInt + CheckedNeg + CheckedRem,
+{
+    while !b.is_zero() {
+        // The only remainder that overflows is `MIN % -1`, which is zero
+        let remainder = a.checked_rem(&b).unwrap_or_else(T::zero);
+        a = b;
+        b = remainder;
+    }
+    if a < T::zero() {
+        a.checked_neg().unwrap_or(a)
+    } else {
+        a
+    }
+}
+
+macro_rules! impl_number_theory_unsigned {
+    ($($unsigned: ty => $signed: ty),+) => {
+        $(
+            impl NonZero<$unsigned> {
+                /// The greatest common divisor of the two values
+                #[must_use]
+                pub fn gcd(self, other: Self) -> Self {
+                    // SAFETY: the greatest common divisor of nonzero values is nonzero
+                    unsafe { Self::new_unchecked(gcd(self.value.get(), other.value.get())) }
+                }
+
+                /// The least common multiple of the two values.
+                /// Returns `None` if it does not fit in the type.
+                pub fn lcm(self, other: Self) -> Option<Self> {
+                    let quotient = self.value.get() / self.gcd(other).value.get();
+                    match quotient.checked_mul(other.value.get()) {
+                        // SAFETY: the product of nonzero values is nonzero
+                        Some(lcm) => Some(unsafe { Self::new_unchecked_const(lcm) }),
+                        None => None,
+                    }
+                }
+
+                /// Whether the two values have no common divisor other than `1`
+                pub fn is_coprime(self, other: Self) -> bool {
+                    self.gcd(other).value.get() == 1
+                }
+
+                /// The greatest common divisor `g` of the two values,
+                /// along with Bézout coefficients `x` and `y` such that `self * x + other * y == g`.
+                ///
+                /// The coefficients are the smallest such pair,
+                /// so they always fit in the signed integer of the same width.
+                pub const fn extended_gcd(self, other: Self) -> (Self, $signed, $signed) {
+                    // The coefficients of the extended Euclidean algorithm alternate in sign,
+                    // so only their magnitudes are tracked, which keeps every step in range.
+                    let (mut r0, mut r1) = (self.value.get(), other.value.get());
+                    let (mut s0, mut s1): ($unsigned, $unsigned) = (1, 0);
+                    let (mut t0, mut t1): ($unsigned, $unsigned) = (0, 1);
+                    let mut odd_step = false;
+                    while r1 != 0 {
+                        let quotient = r0 / r1;
+                        (r0, r1) = (r1, r0 - quotient * r1);
+                        (s0, s1) = (s1, s0 + quotient * s1);
+                        (t0, t1) = (t1, t0 + quotient * t1);
+                        odd_step = !odd_step;
+                    }
+                    let (x, y) = (s0.cast_signed(), t0.cast_signed());
+                    let (x, y) = if odd_step { (-x, y) } else { (x, -y) };
+                    // SAFETY: the greatest common divisor of nonzero values is nonzero
+                    (unsafe { Self::new_unchecked_const(r0) }, x, y)
+                }
+
+                /// Builds a `NonZero` in const contexts without checking the value
+                /// # Safety
+                /// `value` must be nonzero
+                const unsafe fn new_unchecked_const(value: $unsigned) -> Self {
+                    Self {
+                        value: std::num::NonZero::new_unchecked(value),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_number_theory_signed {
+    ($($signed: ty => $unsigned: ty),+) => {
+        $(
+            impl NonZero<$signed> {
+                /// The greatest common divisor of the absolute values
+                #[must_use]
+                pub fn gcd(self, other: Self) -> NonZero<$unsigned> {
+                    self.unsigned_abs().gcd(other.unsigned_abs())
+                }
+
+                /// The least common multiple of the absolute values, which is positive.
+                /// Returns `None` if it does not fit in the type.
+                pub fn lcm(self, other: Self) -> Option<Self> {
+                    match self.unsigned_abs().lcm(other.unsigned_abs()) {
+                        Some(lcm) if lcm.value.get() <= <$signed>::MAX.cast_unsigned() => Some(Self {
+                            value: lcm.value.cast_signed(),
+                        }),
+                        _ => None,
+                    }
+                }
+
+                /// Whether the two values have no common divisor other than `1`
+                pub fn is_coprime(self, other: Self) -> bool {
+                    self.unsigned_abs().is_coprime(other.unsigned_abs())
+                }
+
+                /// The greatest common divisor `g` of the absolute values,
+                /// along with Bézout coefficients `x` and `y` such that `self * x + other * y == g`.
+                ///
+                /// The coefficients are the smallest such pair,
+                /// so they always fit in the type.
+                pub const fn extended_gcd(self, other: Self) -> (NonZero<$unsigned>, $signed, $signed) {
+                    let (gcd, x, y) = self.unsigned_abs().extended_gcd(other.unsigned_abs());
+                    let x = if self.value.is_negative() { -x } else { x };
+                    let y = if other.value.is_negative() { -y } else { y };
+                    (gcd, x, y)
+                }
+            }
+        )+
+    };
+}
+
+impl_number_theory_unsigned!(
+    u8 => i8,
+    u16 => i16,
+    u32 => i32,
+    u64 => i64,
+    u128 => i128,
+    usize => isize
+);
+impl_number_theory_signed!(
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize
+);
@@ -0,0 +1,241 @@
+//! Arithmetic on `NonZero<T>` that preserves the nonzero guarantee
+
+use crate::{NonZero, Nonzeroable};
+use num_traits::{
+    CheckedAdd, CheckedMul, CheckedNeg, CheckedSub, One, SaturatingAdd, SaturatingMul, Unsigned,
+    WrappingAdd,
+};
+use std::ops::{Add, Div, Mul, Rem};
+
+// Operands are taken by value to mirror the std `NonZero` signatures
+#[allow(clippy::needless_pass_by_value)]
+impl<T> NonZero<T>
+where
+    T: Nonzeroable,
+{
+    /// Adds `other` to the nonzero value.
+    /// Returns `None` if the addition overflowed or the sum was zero.
+    pub fn checked_add(self, other: T) -> Option<Self>
+    where
+        T: CheckedAdd,
+    {
+        self.get().checked_add(&other).and_then(Self::new)
+    }
+
+    /// Subtracts `other` from the nonzero value.
+    /// Returns `None` if the subtraction overflowed or the difference was zero.
+    pub fn checked_sub(self, other: T) -> Option<Self>
+    where
+        T: CheckedSub,
+    {
+        self.get().checked_sub(&other).and_then(Self::new)
+    }
+
+    /// Multiplies two nonzero values.
+    /// Returns `None` if the multiplication overflowed.
+    pub fn checked_mul(self, other: Self) -> Option<Self>
+    where
+        T: CheckedMul,
+    {
+        self.get().checked_mul(other.get()).and_then(Self::new)
+    }
+
+    /// Raises the nonzero value to the power of `exp`.
+    /// Returns `None` if the result overflowed.
+    pub fn checked_pow(self, exp: u32) -> Option<Self>
+    where
+        T: Clone + One + CheckedMul,
+    {
+        usize::try_from(exp)
+            .ok()
+            .and_then(|exp| num_traits::checked_pow(self.into_inner(), exp))
+            .and_then(Self::new)
+    }
+
+    /// Negates the nonzero value.
+    /// Returns `None` if the negation overflowed.
+    pub fn checked_neg(self) -> Option<Self>
+    where
+        T: CheckedNeg,
+    {
+        self.get().checked_neg().and_then(Self::new)
+    }
+
+    /// Adds `other` to the nonzero value, clamping at the maximum value of `T`.
+    /// Unsigned addition can only grow the value, so the result is never zero.
+    #[must_use]
+    pub fn saturating_add(self, other: T) -> Self
+    where
+        T: Unsigned + SaturatingAdd,
+    {
+        // SAFETY: the sum of a nonzero value and an unsigned value is at least the nonzero value
+        unsafe { Self::new_unchecked(self.get().saturating_add(&other)) }
+    }
+
+    /// Multiplies two nonzero values, clamping at the bounds of `T`.
+    /// The product of nonzero values is either exact or saturated, so it is never zero.
+    #[must_use]
+    pub fn saturating_mul(self, other: Self) -> Self
+    where
+        T: SaturatingMul,
+    {
+        // SAFETY: neither an exact product of nonzero values nor a bound of `T` is zero
+        unsafe { Self::new_unchecked(self.get().saturating_mul(other.get())) }
+    }
+
+    /// Adds `other` to the nonzero value, wrapping from the maximum value of `T` back to `1`.
+    /// Zero is skipped, so `MAX + 1` is `1` and `MAX + 2` is `2`.
+    #[must_use]
+    pub fn wrapping_add_nonzero(self, other: T) -> Self
+    where
+        T: Unsigned + WrappingAdd + PartialOrd,
+    {
+        let sum = self.get().wrapping_add(&other);
+        let sum = if sum < *self.get() {
+            // Wrapping passed over zero, so step over it.
+            // This cannot overflow, as a wrapped sum is at most `MAX - 1`.
+            sum + T::one()
+        } else {
+            sum
+        };
+        // SAFETY: an unwrapped sum is at least the nonzero value, and a wrapped sum was incremented
+        unsafe { Self::new_unchecked(sum) }
+    }
+}
+
+/// The sum of two unsigned nonzero values is nonzero.
+/// Panics if the addition overflows.
+impl<T> Add for NonZero<T>
+where
+    T: Nonzeroable + Unsigned + CheckedAdd,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs.into_inner())
+            .unwrap_or_else(|| panic!("attempt to add with overflow"))
+    }
+}
+
+/// The product of two nonzero values is nonzero.
+/// Panics if the multiplication overflows.
+impl<T> Mul for NonZero<T>
+where
+    T: Nonzeroable + CheckedMul,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs)
+            .unwrap_or_else(|| panic!("attempt to multiply with overflow"))
+    }
+}
+
+/// Division of `Self` by a [`NonZero`] divisor, which can never divide by zero.
+///
+/// For signed integers, dividing `MIN` by `-1` overflows.
+/// As with the primitive operators, the quotient methods panic in that case,
+/// while the remainder methods return the mathematically correct `0`.
+pub trait DivNonZero: Nonzeroable {
+    /// Divides `self` by `rhs`, rounding the quotient towards positive infinity
+    /// # Panics
+    /// Panics if the quotient overflows
+    #[must_use]
+    fn div_ceil_nonzero(self, rhs: NonZero<Self>) -> Self;
+
+    /// Divides `self` by `rhs`, rounding so that the remainder is never negative
+    /// # Panics
+    /// Panics if the quotient overflows
+    #[must_use]
+    fn div_euclid_nonzero(self, rhs: NonZero<Self>) -> Self;
+
+    /// The remainder of `self.div_euclid_nonzero(rhs)`, which is never negative
+    #[must_use]
+    fn rem_euclid_nonzero(self, rhs: NonZero<Self>) -> Self;
+}
+
+macro_rules! impl_div_unsigned {
+    ($($type: ty),+) => {
+        $(
+            impl Div<NonZero<$type>> for $type {
+                type Output = Self;
+
+                fn div(self, rhs: NonZero<$type>) -> Self::Output {
+                    self / rhs.value
+                }
+            }
+
+            impl Rem<NonZero<$type>> for $type {
+                type Output = Self;
+
+                fn rem(self, rhs: NonZero<$type>) -> Self::Output {
+                    self % rhs.value
+                }
+            }
+
+            impl DivNonZero for $type {
+                fn div_ceil_nonzero(self, rhs: NonZero<Self>) -> Self {
+                    self.div_ceil(rhs.into_inner())
+                }
+
+                fn div_euclid_nonzero(self, rhs: NonZero<Self>) -> Self {
+                    self / rhs
+                }
+
+                fn rem_euclid_nonzero(self, rhs: NonZero<Self>) -> Self {
+                    self % rhs
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_div_signed {
+    ($($type: ty),+) => {
+        $(
+            /// Panics if `self` is `MIN` and `rhs` is `-1`, as the quotient overflows
+            impl Div<NonZero<$type>> for $type {
+                type Output = Self;
+
+                fn div(self, rhs: NonZero<$type>) -> Self::Output {
+                    self / rhs.into_inner()
+                }
+            }
+
+            /// Never panics, as the remainder of `MIN / -1` is `0`
+            impl Rem<NonZero<$type>> for $type {
+                type Output = Self;
+
+                fn rem(self, rhs: NonZero<$type>) -> Self::Output {
+                    self.wrapping_rem(rhs.into_inner())
+                }
+            }
+
+            impl DivNonZero for $type {
+                fn div_ceil_nonzero(self, rhs: NonZero<Self>) -> Self {
+                    let quotient = self / rhs;
+                    let remainder = self % rhs;
+                    // The quotient was truncated towards zero,
+                    // so it needs rounding up when the exact quotient was positive.
+                    // This cannot overflow, as a truncated quotient is smaller than `self`.
+                    if remainder != 0 && (remainder > 0) == rhs.is_positive() {
+                        quotient + 1
+                    } else {
+                        quotient
+                    }
+                }
+
+                fn div_euclid_nonzero(self, rhs: NonZero<Self>) -> Self {
+                    self.div_euclid(rhs.into_inner())
+                }
+
+                fn rem_euclid_nonzero(self, rhs: NonZero<Self>) -> Self {
+                    self.wrapping_rem_euclid(rhs.into_inner())
+                }
+            }
+        )+
+    };
+}
+
+impl_div_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_div_signed!(i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,41 @@
+//! Parsing `NonZero<T>` from strings
+
+use crate::{NonZero, Nonzeroable, ParseNonZeroError};
+use num_traits::Num;
+use std::str::FromStr;
+
+impl<T> NonZero<T>
+where
+    T: Nonzeroable,
+{
+    /// Parses a nonzero value from a string of digits in the given radix
+    /// # Errors
+    /// Returns a [`ParseNonZeroError`] describing why `src` is not a valid nonzero value
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseNonZeroError>
+    where
+        T: Num,
+        T::FromStrRadixErr: Into<ParseNonZeroError>,
+    {
+        if src.is_empty() {
+            return Err(ParseNonZeroError::Empty);
+        }
+        let value = T::from_str_radix(src, radix).map_err(Into::into)?;
+        Self::new(value).ok_or(ParseNonZeroError::Zero)
+    }
+}
+
+impl<T> FromStr for NonZero<T>
+where
+    T: Nonzeroable + FromStr,
+    T::Err: Into<ParseNonZeroError>,
+{
+    type Err = ParseNonZeroError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseNonZeroError::Empty);
+        }
+        let value = s.parse::<T>().map_err(Into::into)?;
+        Self::new(value).ok_or(ParseNonZeroError::Zero)
+    }
+}
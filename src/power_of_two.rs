@@ -0,0 +1,87 @@
+//! Powers of two, refined from `NonZero<T>`
+
+use crate::{NonZero, Nonzeroable};
+use num_traits::{PrimInt, Unsigned};
+use std::ops::{Div, Rem};
+
+/// An unsigned integer that is known to be a power of two,
+/// meaning it is nonzero and has exactly one bit set.
+pub struct PowerOfTwo<T: Nonzeroable> {
+    value: NonZero<T>,
+}
+
+forward_traits!(PowerOfTwo, value);
+
+impl<T> PowerOfTwo<T>
+where
+    T: Nonzeroable + PrimInt + Unsigned,
+{
+    /// Returns a new `PowerOfTwo<T>` if `value` has exactly one bit set
+    pub fn new(value: NonZero<T>) -> Option<Self> {
+        (value.get().count_ones() == 1).then_some(Self { value })
+    }
+
+    /// The power of two as a `NonZero<T>`
+    pub const fn get(&self) -> &NonZero<T> {
+        &self.value
+    }
+
+    /// The exponent of the power of two, which is the shift that divides by it
+    pub fn shift(&self) -> u32 {
+        self.value.get().trailing_zeros()
+    }
+
+    /// The bits below the power of two, which masks off the remainder of dividing by it
+    pub fn mask(&self) -> T {
+        *self.value.get() - T::one()
+    }
+
+    /// Rounds `x` up to the nearest multiple of the power of two.
+    /// Returns `None` if the result does not fit in `T`.
+    pub fn align_up(&self, x: T) -> Option<T> {
+        let mask = self.mask();
+        x.checked_add(&mask).map(|x| x & !mask)
+    }
+
+    /// Rounds `x` down to the nearest multiple of the power of two
+    pub fn align_down(&self, x: T) -> T {
+        x & !self.mask()
+    }
+}
+
+impl<T> From<PowerOfTwo<T>> for NonZero<T>
+where
+    T: Nonzeroable,
+{
+    fn from(value: PowerOfTwo<T>) -> Self {
+        value.value
+    }
+}
+
+macro_rules! impl_div_power_of_two {
+    ($($type: ty),+) => {
+        $(
+            /// Divides by shifting right
+            impl Div<PowerOfTwo<$type>> for $type {
+                type Output = Self;
+
+                #[allow(clippy::suspicious_arithmetic_impl)]
+                fn div(self, rhs: PowerOfTwo<$type>) -> Self::Output {
+                    self >> rhs.shift()
+                }
+            }
+
+            /// Takes the remainder by masking
+            impl Rem<PowerOfTwo<$type>> for $type {
+                type Output = Self;
+
+                #[allow(clippy::suspicious_arithmetic_impl)]
+                fn rem(self, rhs: PowerOfTwo<$type>) -> Self::Output {
+                    self & rhs.mask()
+                }
+            }
+        )+
+    };
+}
+
+impl_div_power_of_two!(u8, u16, u32, u64, u128, usize);
@@ -0,0 +1,268 @@
+//! Rational numbers with a `NonZero<T>` denominator
+
+use crate::{number_theory::gcd, NonZero, Nonzeroable, ParseNonZeroError};
+use num_traits::{CheckedNeg, CheckedRem, PrimInt};
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+/// The integers that a [`Rational`] can be built from
+pub trait RationalInteger:
+    Nonzeroable<Repr: Copy + Eq> + PrimInt + CheckedNeg + CheckedRem
+{
+}
+
+impl<T> RationalInteger for T where
+    T: Nonzeroable<Repr: Copy + Eq> + PrimInt + CheckedNeg + CheckedRem
+{
+}
+
+/// A rational number `numerator / denominator`, whose denominator is known to be nonzero.
+///
+/// Values are always in lowest terms with a positive denominator,
+/// so the sign is carried by the numerator and zero is `0/1`,
+/// and equal values have equal parts.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Rational<T: RationalInteger> {
+    numerator: T,
+    denominator: NonZero<T>,
+}
+
+impl<T> Rational<T>
+where
+    T: RationalInteger,
+{
+    /// Returns the rational number `numerator / denominator` in lowest terms.
+    /// Returns `None` if moving the sign onto the numerator overflowed, as in `1 / i8::MIN`.
+    pub fn new(numerator: T, denominator: NonZero<T>) -> Option<Self> {
+        Self::normalize(numerator, denominator.into_inner())
+    }
+
+    /// The numerator, which carries the sign of the rational number
+    pub const fn numerator(&self) -> T {
+        self.numerator
+    }
+
+    /// The denominator, which is always positive
+    pub const fn denominator(&self) -> NonZero<T> {
+        self.denominator
+    }
+
+    /// Whether the rational number is zero
+    pub fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+
+    /// The reciprocal `denominator / numerator`.
+    /// Returns `None` if the rational number is zero or the reciprocal overflowed.
+    pub fn recip(self) -> Option<Self> {
+        Self::new(*self.denominator.get(), NonZero::new(self.numerator)?)
+    }
+
+    /// Adds two rational numbers.
+    /// Returns `None` if the result overflowed, or if rewriting the numerators
+    /// over the least common denominator or adding them overflowed.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let (left, right, denominator) = self.common_denominator(other)?;
+        Self::normalize(left.checked_add(&right)?, denominator)
+    }
+
+    /// Subtracts `other` from `self`.
+    /// Returns `None` if the result overflowed, or if rewriting the numerators
+    /// over the least common denominator or subtracting them overflowed.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        let (left, right, denominator) = self.common_denominator(other)?;
+        Self::normalize(left.checked_sub(&right)?, denominator)
+    }
+
+    /// Multiplies two rational numbers.
+    /// Returns `None` if the result overflowed.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        // Cancelling across the fractions first keeps the products as small as possible
+        let left_gcd = gcd(self.numerator, *other.denominator.get());
+        let right_gcd = gcd(other.numerator, *self.denominator.get());
+        let numerator = (self.numerator / left_gcd).checked_mul(&(other.numerator / right_gcd))?;
+        let denominator = (*self.denominator.get() / right_gcd)
+            .checked_mul(&(*other.denominator.get() / left_gcd))?;
+        Self::normalize(numerator, denominator)
+    }
+
+    /// Divides `self` by `other`.
+    /// Returns `None` if `other` is zero or the result overflowed.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        // Cancelling across the fractions leaves the result in lowest terms,
+        // so unlike taking the reciprocal first, only a result that does not fit overflows
+        let numerator_gcd = gcd(self.numerator, other.numerator);
+        let denominator_gcd = gcd(*self.denominator.get(), *other.denominator.get());
+        let mut multiplier = *other.denominator.get() / denominator_gcd;
+        let mut divisor = other.numerator / numerator_gcd;
+        if divisor < T::zero() {
+            // The multiplier is positive, so only negating the divisor can overflow,
+            // in which case the denominator of the result does not fit
+            multiplier = multiplier.checked_neg()?;
+            divisor = divisor.checked_neg()?;
+        }
+        let numerator = (self.numerator / numerator_gcd).checked_mul(&multiplier)?;
+        let denominator = (*self.denominator.get() / denominator_gcd).checked_mul(&divisor)?;
+        Self::normalize(numerator, denominator)
+    }
+
+    /// Rewrites both fractions over their least common denominator,
+    /// returning the two numerators and the denominator
+    fn common_denominator(self, other: Self) -> Option<(T, T, T)> {
+        let (left, right) = (*self.denominator.get(), *other.denominator.get());
+        let gcd = gcd(left, right);
+        let left_numerator = self.numerator.checked_mul(&(right / gcd))?;
+        let right_numerator = other.numerator.checked_mul(&(left / gcd))?;
+        Some((
+            left_numerator,
+            right_numerator,
+            left.checked_mul(&(right / gcd))?,
+        ))
+    }
+
+    /// Reduces `numerator / denominator` to lowest terms with a positive denominator.
+    /// `denominator` must be nonzero.
+    fn normalize(numerator: T, denominator: T) -> Option<Self> {
+        if numerator.is_zero() {
+            return Some(Self::from(numerator));
+        }
+        let gcd = gcd(numerator, denominator);
+        let (mut numerator, mut denominator) = (numerator / gcd, denominator / gcd);
+        if denominator < T::zero() {
+            numerator = numerator.checked_neg()?;
+            denominator = denominator.checked_neg()?;
+        }
+        Some(Self {
+            numerator,
+            // SAFETY: dividing a nonzero value by one of its divisors leaves it nonzero
+            denominator: unsafe { NonZero::new_unchecked(denominator) },
+        })
+    }
+}
+
+/// Splits `a / b` into a quotient rounded down and a remainder in `0..b`, where `b` is positive
+fn floor_div<T>(a: T, b: T) -> (T, T)
+where
+    T: RationalInteger,
+{
+    let (quotient, remainder) = (a / b, a % b);
+    if remainder < T::zero() {
+        (quotient - T::one(), remainder + b)
+    } else {
+        (quotient, remainder)
+    }
+}
+
+impl<T> From<T> for Rational<T>
+where
+    T: RationalInteger,
+{
+    fn from(value: T) -> Self {
+        Self {
+            numerator: value,
+            // SAFETY: one is nonzero
+            denominator: unsafe { NonZero::new_unchecked(T::one()) },
+        }
+    }
+}
+
+impl<T> Hash for Rational<T>
+where
+    T: RationalInteger + Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.numerator.hash(state);
+        self.denominator.get().hash(state);
+    }
+}
+
+impl<T> PartialOrd for Rational<T>
+where
+    T: RationalInteger,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares by expanding both values as continued fractions,
+/// so unlike cross-multiplying, this never overflows.
+impl<T> Ord for Rational<T>
+where
+    T: RationalInteger,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (mut a, mut b) = (self.numerator, *self.denominator.get());
+        let (mut c, mut d) = (other.numerator, *other.denominator.get());
+        let mut reversed = false;
+        loop {
+            let (left_quotient, left_remainder) = floor_div(a, b);
+            let (right_quotient, right_remainder) = floor_div(c, d);
+            let ordering = match (left_remainder.is_zero(), right_remainder.is_zero()) {
+                _ if left_quotient != right_quotient => left_quotient.cmp(&right_quotient),
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (false, false) => {
+                    // Comparing the fractional parts `r1 / b` and `r2 / d`
+                    // is the reverse of comparing `b / r1` and `d / r2`
+                    (a, b, c, d) = (b, left_remainder, d, right_remainder);
+                    reversed = !reversed;
+                    continue;
+                }
+            };
+            return if reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+        }
+    }
+}
+
+impl<T> Debug for Rational<T>
+where
+    T: RationalInteger + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Rational")
+            .field("numerator", &self.numerator)
+            .field("denominator", &self.denominator)
+            .finish()
+    }
+}
+
+impl<T> Display for Rational<T>
+where
+    T: RationalInteger + Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+/// Parses either `numerator/denominator` or a plain integer
+impl<T> FromStr for Rational<T>
+where
+    T: RationalInteger + FromStr,
+    T::Err: Into<ParseNonZeroError>,
+{
+    type Err = ParseNonZeroError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (numerator, denominator) = s.split_once('/').unwrap_or((s, "1"));
+        if numerator.is_empty() {
+            return Err(ParseNonZeroError::Empty);
+        }
+        let numerator = numerator.parse::<T>().map_err(Into::into)?;
+        let denominator = denominator.parse::<NonZero<T>>()?;
+        Self::new(numerator, denominator).ok_or(ParseNonZeroError::PosOverflow)
+    }
+}
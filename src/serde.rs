@@ -0,0 +1,33 @@
+//! Serializing `NonZero<T>` as its nonzero value, rejecting zero when deserializing
+
+use crate::{NonZero, Nonzeroable};
+use serde::{
+    de::{Error, Unexpected},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+impl<T> Serialize for NonZero<T>
+where
+    T: Nonzeroable + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for NonZero<T>
+where
+    T: Nonzeroable + Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = T::deserialize(deserializer)?;
+        Self::new(value)
+            .ok_or_else(|| D::Error::invalid_value(Unexpected::Other("zero"), &"a nonzero value"))
+    }
+}
@@ -0,0 +1,176 @@
+//! Sign-aware operations on signed `NonZero<T>`, and the `Positive<T>` and `Negative<T>` refinements
+
+use crate::{NonZero, Nonzeroable, SignError};
+use num_traits::{PrimInt, Signed};
+use std::ops::{Add, Mul, Neg};
+
+impl<T> NonZero<T>
+where
+    T: Nonzeroable + Signed,
+{
+    /// The absolute value of the nonzero value.
+    /// Like the primitive `abs`, taking the absolute value of `T::MIN` overflows.
+    #[must_use]
+    pub fn abs(self) -> Self {
+        // SAFETY: the absolute value of a nonzero value is nonzero, even when it overflows to `T::MIN`
+        unsafe { Self::new_unchecked(self.get().abs()) }
+    }
+
+    /// Returns `1` if the value is positive and `-1` if the value is negative
+    #[must_use]
+    pub fn signum(self) -> Self {
+        // SAFETY: the signum of a nonzero value is either 1 or -1
+        unsafe { Self::new_unchecked(self.get().signum()) }
+    }
+
+    /// Whether the value is positive
+    pub fn is_positive(&self) -> bool {
+        self.get().is_positive()
+    }
+
+    /// Whether the value is negative
+    pub fn is_negative(&self) -> bool {
+        self.get().is_negative()
+    }
+}
+
+macro_rules! impl_unsigned_abs {
+    ($signed: ty, $unsigned: ty) => {
+        impl NonZero<$signed> {
+            /// The absolute value of the nonzero value, without overflow
+            #[must_use]
+            pub const fn unsigned_abs(self) -> NonZero<$unsigned> {
+                // SAFETY: the absolute value of a nonzero value is nonzero
+                NonZero {
+                    value: self.value.unsigned_abs(),
+                }
+            }
+        }
+    };
+}
+
+impl_unsigned_abs!(i8, u8);
+impl_unsigned_abs!(i16, u16);
+impl_unsigned_abs!(i32, u32);
+impl_unsigned_abs!(i64, u64);
+impl_unsigned_abs!(i128, u128);
+impl_unsigned_abs!(isize, usize);
+
+/// A signed integer that is known to be greater than zero.
+pub struct Positive<T: Nonzeroable> {
+    value: NonZero<T>,
+}
+
+/// A signed integer that is known to be less than zero.
+pub struct Negative<T: Nonzeroable> {
+    value: NonZero<T>,
+}
+
+forward_traits!(Positive, value);
+forward_traits!(Negative, value);
+
+/// Implements the constructors and conversions shared by `Positive<T>` and `Negative<T>`
+macro_rules! impl_sign_refinement {
+    ($type: ident, $is_sign: ident, $sign: literal) => {
+        impl<T> $type<T>
+        where
+            T: Nonzeroable + PrimInt + Signed,
+        {
+            #[doc = concat!("Returns a new `", stringify!($type), "<T>` if `value` is ", $sign)]
+            pub fn new(value: T) -> Option<Self> {
+                NonZero::new(value).and_then(|value| Self::try_from(value).ok())
+            }
+
+            /// The value as a `NonZero<T>`
+            pub const fn get(&self) -> &NonZero<T> {
+                &self.value
+            }
+
+            /// Adds two values of the same sign, which keeps that sign.
+            /// Returns `None` if the addition overflowed.
+            pub fn checked_add(self, other: Self) -> Option<Self> {
+                let value = self.value.checked_add(other.value.into_inner())?;
+                Some(Self { value })
+            }
+        }
+
+        impl<T> TryFrom<NonZero<T>> for $type<T>
+        where
+            T: Nonzeroable + PrimInt + Signed,
+        {
+            type Error = SignError<T>;
+
+            fn try_from(value: NonZero<T>) -> Result<Self, Self::Error> {
+                if value.$is_sign() {
+                    Ok(Self { value })
+                } else {
+                    Err(SignError::new(value))
+                }
+            }
+        }
+
+        impl<T> From<$type<T>> for NonZero<T>
+        where
+            T: Nonzeroable,
+        {
+            fn from(value: $type<T>) -> Self {
+                value.value
+            }
+        }
+
+        /// The sum of two values of the same sign keeps that sign.
+        /// Panics if the addition overflows.
+        impl<T> Add for $type<T>
+        where
+            T: Nonzeroable + PrimInt + Signed,
+        {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                self.checked_add(rhs)
+                    .unwrap_or_else(|| panic!("attempt to add with overflow"))
+            }
+        }
+    };
+}
+
+impl_sign_refinement!(Positive, is_positive, "positive");
+impl_sign_refinement!(Negative, is_negative, "negative");
+
+/// Implements multiplication between `Positive<T>` and `Negative<T>`, following the rule of signs
+macro_rules! impl_sign_mul {
+    ($lhs: ident * $rhs: ident = $output: ident) => {
+        /// Panics if the multiplication overflows.
+        impl<T> Mul<$rhs<T>> for $lhs<T>
+        where
+            T: Nonzeroable + PrimInt + Signed,
+        {
+            type Output = $output<T>;
+
+            fn mul(self, rhs: $rhs<T>) -> Self::Output {
+                $output {
+                    value: self.value * rhs.value,
+                }
+            }
+        }
+    };
+}
+
+impl_sign_mul!(Positive * Positive = Positive);
+impl_sign_mul!(Positive * Negative = Negative);
+impl_sign_mul!(Negative * Positive = Negative);
+impl_sign_mul!(Negative * Negative = Positive);
+
+/// Negating a positive value never overflows, as `-T::MAX` fits in `T`
+impl<T> Neg for Positive<T>
+where
+    T: Nonzeroable + PrimInt + Signed,
+{
+    type Output = Negative<T>;
+
+    fn neg(self) -> Self::Output {
+        // SAFETY: the negation of a positive value is nonzero
+        let value = unsafe { NonZero::new_unchecked(-self.value.into_inner()) };
+        Negative { value }
+    }
+}
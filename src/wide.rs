@@ -0,0 +1,105 @@
+//! Double-width arithmetic for reducing by a precomputed reciprocal
+
+use num_traits::PrimInt;
+
+/// The high half of the full product of two integers
+pub trait MulHi: Sized {
+    /// The upper `Self::BITS` bits of `self * other`
+    fn mul_hi(self, other: Self) -> Self;
+}
+
+macro_rules! impl_mul_hi_widening {
+    ($($type: ty => $wide: ty),+) => {
+        $(
+            impl MulHi for $type {
+                #[allow(clippy::cast_possible_truncation)]
+                #[inline]
+                fn mul_hi(self, other: Self) -> Self {
+                    ((<$wide>::from(self) * <$wide>::from(other)) >> <$type>::BITS) as $type
+                }
+            }
+        )+
+    };
+}
+
+impl_mul_hi_widening!(u8 => u16, u16 => u32, u32 => u64, u64 => u128);
+impl_mul_hi_widening!(i8 => i16, i16 => i32, i32 => i64, i64 => i128);
+
+/// `usize` is at most 64 bits wide on every supported target, so `u128` is wide enough
+impl MulHi for usize {
+    #[allow(clippy::cast_possible_truncation)]
+    #[inline]
+    fn mul_hi(self, other: Self) -> Self {
+        ((self as u128 * other as u128) >> Self::BITS) as Self
+    }
+}
+
+/// `isize` is at most 64 bits wide on every supported target, so `i128` is wide enough
+impl MulHi for isize {
+    #[allow(clippy::cast_possible_truncation)]
+    #[inline]
+    fn mul_hi(self, other: Self) -> Self {
+        ((self as i128 * other as i128) >> Self::BITS) as Self
+    }
+}
+
+/// No wider type exists, so the product is built from 64-bit halves
+impl MulHi for u128 {
+    #[inline]
+    fn mul_hi(self, other: Self) -> Self {
+        const LOW: u128 = u64::MAX as u128;
+        let (a_low, a_high) = (self & LOW, self >> 64);
+        let (b_low, b_high) = (other & LOW, other >> 64);
+        // None of these overflow, as each product of halves is at most `(2^64 - 1)^2`
+        let cross = ((a_low * b_low) >> 64) + ((a_high * b_low) & LOW) + a_low * b_high;
+        a_high * b_high + ((a_high * b_low) >> 64) + (cross >> 64)
+    }
+}
+
+/// The unsigned product, corrected for the operands being read as unsigned
+impl MulHi for i128 {
+    #[inline]
+    fn mul_hi(self, other: Self) -> Self {
+        let high = self
+            .cast_unsigned()
+            .mul_hi(other.cast_unsigned())
+            .cast_signed();
+        // Reading a negative operand as unsigned adds `2^128` to it,
+        // which adds the other operand to the high half
+        high.wrapping_sub(other & (self >> 127))
+            .wrapping_sub(self & (other >> 127))
+    }
+}
+
+/// An unsigned integer with an unsigned type twice its width
+pub trait Widen: Sized {
+    /// The unsigned type twice as wide as `Self`
+    type Wide: PrimInt + MulHi;
+
+    /// Converts to the wide type without loss
+    fn widen(self) -> Self::Wide;
+
+    /// Converts back from the wide type, keeping the low bits
+    fn truncate(wide: Self::Wide) -> Self;
+}
+
+macro_rules! impl_widen {
+    ($($type: ty => $wide: ty),+) => {
+        $(
+            impl Widen for $type {
+                type Wide = $wide;
+
+                fn widen(self) -> Self::Wide {
+                    self.into()
+                }
+
+                #[allow(clippy::cast_possible_truncation)]
+                fn truncate(wide: Self::Wide) -> Self {
+                    wide as $type
+                }
+            }
+        )+
+    };
+}
+
+impl_widen!(u8 => u16, u16 => u32, u32 => u64, u64 => u128);
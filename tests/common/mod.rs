@@ -0,0 +1,46 @@
+//! Inputs and reference implementations shared by the integration tests
+
+// Each test binary includes this module but only uses some of it
+#![allow(dead_code)]
+
+use num_traits::PrimInt;
+
+/// A small xorshift generator, so the tests need no dependencies
+pub const fn pseudo_random(state: &mut u128) -> u128 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+/// The seed for `pseudo_random`
+pub const SEED: u128 = 0x9e37_79b9_7f4a_7c15_f39c_c060_5ced_c834;
+
+/// The greatest common divisor by Euclid's algorithm, which is never negative
+pub const fn reference_gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a.abs()
+    } else {
+        reference_gcd(b, a % b)
+    }
+}
+
+/// Small values, and the values around the middle and the bounds of `T`,
+/// where rounding and overflow tend to go wrong
+pub fn edge_values<T: PrimInt>() -> Vec<T> {
+    let (min, max) = (T::min_value(), T::max_value());
+    let small = [0, 1, 2, 3, 5, 7, 10].map(|value| T::from(value).unwrap_or_else(T::zero));
+    let half = max / (T::one() + T::one());
+    let mut values = small.to_vec();
+    values.extend([
+        half,
+        half + T::one(),
+        half + T::one() + T::one(),
+        max - T::one(),
+        max,
+    ]);
+    values.extend([min, min + T::one()]);
+    values.sort_unstable();
+    values.dedup();
+    values
+}
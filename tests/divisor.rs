@@ -0,0 +1,83 @@
+//! `Divisor` against the native `/` and `%`
+
+mod common;
+
+use beetle_nonzero::{nonzero, Divisor, NonZero};
+use common::{edge_values, pseudo_random, SEED};
+
+/// Checks `/` and `%` against the native operators, skipping the overflowing `MIN / -1`
+macro_rules! check_against_native {
+    ($type: ty, $divisor: expr, $dividend: expr) => {{
+        let (d, n): ($type, $type) = ($divisor, $dividend);
+        if let Some(nonzero) = NonZero::new(d) {
+            let divisor = Divisor::from(nonzero);
+            if let Some(quotient) = n.checked_div(d) {
+                assert_eq!(n / divisor, quotient, "{n} / {d}");
+            }
+            assert_eq!(n % divisor, n.wrapping_rem(d), "{n} % {d}");
+        }
+    }};
+}
+
+#[test]
+fn every_u8_and_i8_pair() {
+    for d in u8::MIN..=u8::MAX {
+        for n in u8::MIN..=u8::MAX {
+            check_against_native!(u8, d, n);
+        }
+    }
+    for d in i8::MIN..=i8::MAX {
+        for n in i8::MIN..=i8::MAX {
+            check_against_native!(i8, d, n);
+        }
+    }
+}
+
+macro_rules! check_edges {
+    ($($type: ty),+) => {
+        $(
+            let mut divisors = edge_values::<$type>();
+            divisors.extend((1..<$type>::BITS - 1).map(|shift| 1 << shift));
+            #[allow(unused_comparisons)]
+            if <$type>::MIN < 0 {
+                divisors.extend(divisors.clone().into_iter().map(<$type>::wrapping_neg));
+            }
+
+            let mut state = SEED;
+            for d in divisors {
+                let mut dividends = edge_values::<$type>();
+                dividends.extend([d, d.wrapping_sub(1), d.wrapping_add(1)]);
+                dividends.extend((0..64).map(|_| pseudo_random(&mut state) as $type));
+                for n in dividends {
+                    check_against_native!($type, d, n);
+                }
+            }
+            for _ in 0..10_000 {
+                let random = pseudo_random(&mut state);
+                let d = (random as $type) >> (random % u128::from(<$type>::BITS - 1));
+                check_against_native!($type, d, pseudo_random(&mut state) as $type);
+            }
+        )+
+    };
+}
+
+#[test]
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+fn edges_and_random_values_of_every_width() {
+    check_edges!(u16, u32, u64, u128, usize, i16, i32, i64, i128, isize);
+}
+
+#[test]
+fn min_by_one_and_minus_one() {
+    let min = i64::MIN;
+    assert_eq!(min / Divisor::from(nonzero!(1i64)), min);
+    assert_eq!(min / Divisor::from(nonzero!(-2i64)), 1 << 62);
+    assert_eq!(min % Divisor::from(nonzero!(-1i64)), 0);
+    assert_eq!(min / Divisor::from(nonzero!(i64::MIN)), 1);
+}
+
+#[test]
+#[should_panic = "attempt to divide with overflow"]
+fn min_divided_by_minus_one_panics() {
+    let _ = std::hint::black_box(i32::MIN) / Divisor::from(nonzero!(-1i32));
+}
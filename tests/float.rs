@@ -0,0 +1,87 @@
+//! `NonZeroFloat<F>` excludes zeros, NaN and infinities, which gives it a total order
+
+use beetle_nonzero::NonZeroFloat;
+use std::{
+    cmp::Ordering,
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn rejects_zeros_nan_and_infinities() {
+    for value in [
+        0.0,
+        -0.0,
+        f64::NAN,
+        -f64::NAN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ] {
+        assert!(NonZeroFloat::new(value).is_none(), "accepted {value}");
+    }
+    for value in [0.0f32, -0.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+        assert!(NonZeroFloat::new(value).is_none(), "accepted {value}");
+    }
+}
+
+#[test]
+fn accepts_finite_nonzero_values() {
+    let subnormal = f64::from_bits(1);
+    for value in [1.0, -1.0, f64::MAX, f64::MIN, f64::MIN_POSITIVE, subnormal] {
+        assert_eq!(NonZeroFloat::new(value).map(NonZeroFloat::get), Some(value));
+    }
+}
+
+#[test]
+fn recip_of_a_small_subnormal_is_none() {
+    let subnormal = NonZeroFloat::new(f64::from_bits(1)).map(NonZeroFloat::recip);
+    assert_eq!(subnormal, Some(None));
+    let subnormal = NonZeroFloat::new(f32::from_bits(1)).map(NonZeroFloat::recip);
+    assert_eq!(subnormal, Some(None));
+
+    let two = NonZeroFloat::new(2.0f64).and_then(NonZeroFloat::recip);
+    assert_eq!(two.map(NonZeroFloat::get), Some(0.5));
+    // The largest values have subnormal reciprocals, which are still nonzero
+    let max = NonZeroFloat::new(f64::MAX).and_then(NonZeroFloat::recip);
+    assert!(max.is_some_and(|recip| recip.get().is_subnormal()));
+}
+
+#[test]
+fn ord_and_hash_agree_with_equality() {
+    let values: Vec<NonZeroFloat<f64>> = [
+        f64::MIN,
+        -1.5,
+        -f64::MIN_POSITIVE,
+        -f64::from_bits(1),
+        f64::from_bits(1),
+        f64::MIN_POSITIVE,
+        0.3,
+        0.1 + 0.2,
+        1.0,
+        f64::MAX,
+    ]
+    .into_iter()
+    .filter_map(NonZeroFloat::new)
+    .collect();
+    for a in &values {
+        for b in &values {
+            assert_eq!(
+                a.cmp(b),
+                a.get()
+                    .partial_cmp(&b.get())
+                    .unwrap_or_else(|| panic!("NaN"))
+            );
+            assert_eq!(a == b, a.cmp(b) == Ordering::Equal);
+            if a == b {
+                assert_eq!(hash(a), hash(b));
+            }
+        }
+    }
+    assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+}
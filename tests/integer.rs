@@ -0,0 +1,22 @@
+//! The `NonZeroInteger` trait works alongside the inherent `NonZero` methods
+
+use beetle_nonzero::{nonzero, NonZero, NonZeroInteger};
+use std::num::NonZeroI16;
+
+#[test]
+fn importing_the_trait_keeps_get_returning_a_reference() {
+    let value = nonzero!(7u32);
+    let reference: &u32 = value.get();
+    assert_eq!(*reference, 7);
+    assert_eq!(value.to_primitive(), 7);
+}
+
+#[test]
+fn generic_code_covers_both_nonzero_types() {
+    fn double<N: NonZeroInteger>(value: N) -> Option<N> {
+        value.checked_add(value.to_primitive())
+    }
+    assert_eq!(double(nonzero!(-3i16)), Some(nonzero!(-6i16)));
+    assert_eq!(double(NonZeroI16::MIN), None);
+    assert_eq!(double(NonZero::<i16>::MAX), None);
+}
@@ -0,0 +1,131 @@
+//! `Modulus` and `ModInt` against the native `%` on a wider type
+
+mod common;
+
+use beetle_nonzero::{ModInt, Modulus, NonZero};
+use common::{edge_values, pseudo_random, reference_gcd, SEED};
+
+/// `base^exp % modulus` by repeated squaring
+const fn reference_pow(base: u128, mut exp: u32, modulus: u128) -> u128 {
+    let (mut base, mut result) = (base % modulus, 1 % modulus);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result
+}
+
+/// Checks `reduce`, `pow` and `inverse` of `a`, and `+`, `-` and `*` of `a` and `b`
+macro_rules! check_against_native {
+    ($type: ty, $modulus: expr, $a: expr, $b: expr) => {{
+        let (m, a, b): ($type, $type, $type) = ($modulus, $a, $b);
+        let modulus = Modulus::new(NonZero::new(m).unwrap_or_else(|| panic!("zero modulus")));
+        let (wide_m, wide_a, wide_b) = (u128::from(m), u128::from(a), u128::from(b));
+        let (x, y) = (ModInt::new(a, modulus), ModInt::new(b, modulus));
+
+        assert_eq!(modulus.reduce(a), a % m, "{a} % {m}");
+        assert_eq!(
+            u128::from((x + y).get()),
+            (wide_a + wide_b) % wide_m,
+            "{a} + {b} mod {m}"
+        );
+        assert_eq!(
+            u128::from((x - y).get()),
+            (wide_a % wide_m + wide_m - wide_b % wide_m) % wide_m,
+            "{a} - {b} mod {m}"
+        );
+        assert_eq!(
+            u128::from((x * y).get()),
+            wide_a * wide_b % wide_m,
+            "{a} * {b} mod {m}"
+        );
+        for exp in [0, 1, 2, 3, 65_537, u32::MAX] {
+            assert_eq!(
+                u128::from(x.pow(exp).get()),
+                reference_pow(wide_a, exp, wide_m),
+                "{a} ^ {exp} mod {m}"
+            );
+        }
+
+        match x.inverse() {
+            Some(inverse) => {
+                assert!(inverse.get() < m);
+                assert_eq!(
+                    u128::from((inverse * x).get()),
+                    1 % wide_m,
+                    "{a}^-1 mod {m}"
+                );
+            }
+            None => assert!(
+                reference_gcd(i128::from(a % m), i128::from(m)) != 1,
+                "{a}^-1 mod {m}"
+            ),
+        }
+    }};
+}
+
+#[test]
+fn every_u8_modulus_and_pair() {
+    for m in 1..=u8::MAX {
+        let modulus = Modulus::new(NonZero::new(m).unwrap_or_else(|| panic!("zero modulus")));
+        for a in 0..=u8::MAX {
+            check_against_native!(u8, m, a, u8::MAX - a);
+            // Every pair of residues, which is every input the operators see after reducing
+            if a < m {
+                let x = ModInt::new(a, modulus);
+                for b in 0..m {
+                    let (wide_a, wide_b, wide_m) = (u32::from(a), u32::from(b), u32::from(m));
+                    let y = ModInt::new(b, modulus);
+                    assert_eq!(u32::from((x + y).get()), (wide_a + wide_b) % wide_m);
+                    assert_eq!(
+                        u32::from((x - y).get()),
+                        (wide_a + wide_m - wide_b) % wide_m
+                    );
+                    assert_eq!(u32::from((x * y).get()), wide_a * wide_b % wide_m);
+                }
+            }
+        }
+    }
+}
+
+macro_rules! check_edges {
+    ($($type: ty),+) => {
+        $(
+            let values = edge_values::<$type>();
+            for m in values.iter().copied().filter(|&m| m != 0) {
+                for a in values.iter().copied().chain([m - 1]) {
+                    for b in values.iter().copied().chain([m - 1]) {
+                        check_against_native!($type, m, a, b);
+                    }
+                }
+            }
+
+            let mut state = SEED;
+            for _ in 0..2_000 {
+                let random = pseudo_random(&mut state);
+                let m = (random as $type) >> (random % u128::from(<$type>::BITS));
+                let (a, b) = (pseudo_random(&mut state) as $type, pseudo_random(&mut state) as $type);
+                check_against_native!($type, m.max(1), a, b);
+            }
+        )+
+    };
+}
+
+#[test]
+#[allow(clippy::cast_possible_truncation)]
+fn edges_and_random_values_of_every_width() {
+    check_edges!(u16, u32, u64);
+}
+
+#[test]
+#[should_panic = "different moduli"]
+fn combining_different_moduli_panics() {
+    let (seven, eleven) = (
+        Modulus::new(beetle_nonzero::nonzero!(7u32)),
+        Modulus::new(beetle_nonzero::nonzero!(11u32)),
+    );
+    let _ = ModInt::new(3, seven) + ModInt::new(3, eleven);
+}
@@ -0,0 +1,64 @@
+//! `gcd`, `lcm`, `is_coprime` and `extended_gcd` against a plain Euclid reference
+
+mod common;
+
+use beetle_nonzero::{nonzero, NonZero};
+use common::reference_gcd;
+
+#[test]
+fn every_u8_pair() {
+    for a in (1..=u8::MAX).filter_map(NonZero::new) {
+        for b in (1..=u8::MAX).filter_map(NonZero::new) {
+            let (x, y) = (i128::from(a.get_u8()), i128::from(b.get_u8()));
+            let gcd = reference_gcd(x, y);
+            let lcm = x * y / gcd;
+            assert_eq!(i128::from(a.gcd(b).get_u8()), gcd);
+            assert_eq!(
+                a.lcm(b).map(|lcm| i128::from(lcm.get_u8())),
+                (lcm <= 255).then_some(lcm)
+            );
+            assert_eq!(a.is_coprime(b), gcd == 1);
+
+            let (bezout_gcd, s, t) = a.extended_gcd(b);
+            assert_eq!(bezout_gcd, a.gcd(b));
+            assert_eq!(x * i128::from(s) + y * i128::from(t), gcd);
+        }
+    }
+}
+
+#[test]
+fn every_i8_pair() {
+    for a in (i8::MIN..=i8::MAX).filter_map(NonZero::new) {
+        for b in (i8::MIN..=i8::MAX).filter_map(NonZero::new) {
+            let (x, y) = (i128::from(a.get_i8()), i128::from(b.get_i8()));
+            let gcd = reference_gcd(x, y);
+            let lcm = (x * y / gcd).abs();
+            assert_eq!(i128::from(a.gcd(b).get_u8()), gcd);
+            assert_eq!(
+                a.lcm(b).map(|lcm| i128::from(lcm.get_i8())),
+                (lcm <= 127).then_some(lcm)
+            );
+            assert_eq!(a.is_coprime(b), gcd == 1);
+
+            let (bezout_gcd, s, t) = a.extended_gcd(b);
+            assert_eq!(bezout_gcd, a.gcd(b));
+            assert_eq!(x * i128::from(s) + y * i128::from(t), gcd);
+        }
+    }
+}
+
+#[test]
+fn extremes_of_the_widest_types() {
+    let max = nonzero!(u128::MAX);
+    assert_eq!(max.gcd(max), max);
+    assert_eq!(max.lcm(nonzero!(2u128)), None);
+    assert_eq!(
+        max.extended_gcd(nonzero!(u128::MAX - 1)),
+        (nonzero!(1u128), 1, -1)
+    );
+
+    let min = nonzero!(i128::MIN);
+    assert_eq!(min.gcd(min), nonzero!(1u128 << 127));
+    assert_eq!(min.lcm(nonzero!(-1i128)), None);
+    assert_eq!(min.extended_gcd(nonzero!(-1i128)), (nonzero!(1u128), 0, -1));
+}
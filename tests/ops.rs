@@ -0,0 +1,108 @@
+//! Arithmetic on `NonZero<T>` that keeps the nonzero guarantee
+
+use beetle_nonzero::{nonzero, DivNonZero, NonZero};
+
+#[test]
+fn wrapping_add_nonzero_skips_zero() {
+    let max = nonzero!(u8::MAX);
+    assert_eq!(max.wrapping_add_nonzero(1), nonzero!(1u8));
+    assert_eq!(max.wrapping_add_nonzero(2), nonzero!(2u8));
+    assert_eq!(nonzero!(1u8).wrapping_add_nonzero(u8::MAX), nonzero!(1u8));
+    // The wrapped sum is `MAX - 1`, the largest one that is stepped over zero
+    assert_eq!(max.wrapping_add_nonzero(u8::MAX), max);
+    assert_eq!(nonzero!(3u8).wrapping_add_nonzero(0), nonzero!(3u8));
+}
+
+#[test]
+fn wrapping_add_nonzero_matches_counting_past_zero() {
+    for a in (1..=u8::MAX).filter_map(NonZero::new) {
+        for b in 0..=u8::MAX {
+            // Counting `b` steps through `1..=255` from `a`
+            let expected = (u16::from(a.get_u8()) - 1 + u16::from(b)) % 255 + 1;
+            assert_eq!(u16::from(a.wrapping_add_nonzero(b).get_u8()), expected);
+        }
+    }
+}
+
+#[test]
+fn saturating_ops_clamp_at_the_bounds() {
+    let max = nonzero!(u8::MAX);
+    assert_eq!(max.saturating_add(1), max);
+    assert_eq!(nonzero!(200u8).saturating_add(100), max);
+    assert_eq!(nonzero!(200u8).saturating_add(55), max);
+    assert_eq!(nonzero!(200u8).saturating_add(54), nonzero!(254u8));
+    assert_eq!(nonzero!(16u8).saturating_mul(nonzero!(16u8)), max);
+    assert_eq!(
+        nonzero!(-16i8).saturating_mul(nonzero!(16i8)),
+        nonzero!(i8::MIN)
+    );
+}
+
+/// Division rounding towards positive infinity, computed without overflow
+fn reference_div_ceil(a: i8, b: i8) -> i16 {
+    let (a, b) = (i16::from(a), i16::from(b));
+    let quotient = a / b;
+    if a % b != 0 && (a < 0) == (b < 0) {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+#[test]
+fn every_i8_division() {
+    for a in i8::MIN..=i8::MAX {
+        for b in (i8::MIN..=i8::MAX).filter_map(NonZero::new) {
+            let divisor = b.get_i8();
+            assert_eq!(a % b, a.wrapping_rem(divisor));
+            assert_eq!(a.rem_euclid_nonzero(b), a.wrapping_rem_euclid(divisor));
+            if a == i8::MIN && divisor == -1 {
+                continue;
+            }
+            assert_eq!(a / b, a / divisor);
+            assert_eq!(a.div_euclid_nonzero(b), a.div_euclid(divisor));
+            assert_eq!(
+                i16::from(a.div_ceil_nonzero(b)),
+                reference_div_ceil(a, divisor)
+            );
+        }
+    }
+}
+
+#[test]
+fn every_u8_division() {
+    for a in 0..=u8::MAX {
+        for b in (1..=u8::MAX).filter_map(NonZero::new) {
+            let divisor = b.get_u8();
+            assert_eq!(a / b, a / divisor);
+            assert_eq!(a % b, a % divisor);
+            assert_eq!(a.div_ceil_nonzero(b), a.div_ceil(divisor));
+            assert_eq!(a.div_euclid_nonzero(b), a.div_euclid(divisor));
+            assert_eq!(a.rem_euclid_nonzero(b), a.rem_euclid(divisor));
+        }
+    }
+}
+
+#[test]
+fn remainders_of_min_by_minus_one_are_zero() {
+    assert_eq!(i8::MIN % nonzero!(-1i8), 0);
+    assert_eq!(i8::MIN.rem_euclid_nonzero(nonzero!(-1i8)), 0);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn dividing_min_by_minus_one_panics() {
+    let _ = std::hint::black_box(i8::MIN) / nonzero!(-1i8);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn dividing_min_by_minus_one_euclid_panics() {
+    let _ = std::hint::black_box(i8::MIN).div_euclid_nonzero(nonzero!(-1i8));
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn dividing_min_by_minus_one_ceil_panics() {
+    let _ = std::hint::black_box(i8::MIN).div_ceil_nonzero(nonzero!(-1i8));
+}
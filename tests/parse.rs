@@ -0,0 +1,75 @@
+//! Parsing `NonZero<T>` maps each kind of `ParseIntError` to a `ParseNonZeroError`
+
+use beetle_nonzero::{nonzero, NonZero, ParseNonZeroError};
+
+#[test]
+fn parses_nonzero_values() {
+    assert_eq!("7".parse::<NonZero<u8>>(), Ok(nonzero!(7u8)));
+    assert_eq!("-128".parse::<NonZero<i8>>(), Ok(nonzero!(i8::MIN)));
+    assert_eq!("+255".parse::<NonZero<u8>>(), Ok(nonzero!(u8::MAX)));
+    assert_eq!(NonZero::<u8>::from_str_radix("ff", 16), Ok(nonzero!(255u8)));
+    assert_eq!(
+        NonZero::<i8>::from_str_radix("-80", 16),
+        Ok(nonzero!(i8::MIN))
+    );
+}
+
+#[test]
+fn empty_strings_are_empty() {
+    assert_eq!("".parse::<NonZero<u8>>(), Err(ParseNonZeroError::Empty));
+    assert_eq!("".parse::<NonZero<i8>>(), Err(ParseNonZeroError::Empty));
+    assert_eq!(
+        NonZero::<u8>::from_str_radix("", 16),
+        Err(ParseNonZeroError::Empty)
+    );
+}
+
+#[test]
+fn zeros_are_zero() {
+    for zero in ["0", "00", "-0", "+0"] {
+        assert_eq!(zero.parse::<NonZero<i8>>(), Err(ParseNonZeroError::Zero));
+    }
+    // `u8` rejects the sign of `"-0"` before it sees the zero
+    assert_eq!("0".parse::<NonZero<u8>>(), Err(ParseNonZeroError::Zero));
+    assert_eq!("00".parse::<NonZero<u8>>(), Err(ParseNonZeroError::Zero));
+    assert_eq!(
+        "-0".parse::<NonZero<u8>>(),
+        Err(ParseNonZeroError::InvalidDigit)
+    );
+}
+
+#[test]
+fn out_of_range_values_overflow() {
+    assert_eq!(
+        "256".parse::<NonZero<u8>>(),
+        Err(ParseNonZeroError::PosOverflow)
+    );
+    assert_eq!(
+        "128".parse::<NonZero<i8>>(),
+        Err(ParseNonZeroError::PosOverflow)
+    );
+    assert_eq!(
+        "-129".parse::<NonZero<i8>>(),
+        Err(ParseNonZeroError::NegOverflow)
+    );
+    assert_eq!(
+        NonZero::<u8>::from_str_radix("100", 16),
+        Err(ParseNonZeroError::PosOverflow)
+    );
+}
+
+#[test]
+fn other_characters_are_invalid_digits() {
+    assert_eq!(
+        "12a".parse::<NonZero<u8>>(),
+        Err(ParseNonZeroError::InvalidDigit)
+    );
+    assert_eq!(
+        "-".parse::<NonZero<i8>>(),
+        Err(ParseNonZeroError::InvalidDigit)
+    );
+    assert_eq!(
+        NonZero::<u8>::from_str_radix("fg", 16),
+        Err(ParseNonZeroError::InvalidDigit)
+    );
+}
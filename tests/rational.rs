@@ -0,0 +1,108 @@
+//! `Rational<T>` comparisons and arithmetic against exact cross-multiplication in `i128`
+
+mod common;
+
+use beetle_nonzero::{NonZero, Rational};
+use common::reference_gcd;
+use std::cmp::Ordering;
+
+/// Denominators of each sign, including both extremes
+const DENOMINATORS: [i8; 6] = [1, 2, 3, i8::MAX, -1, i8::MIN];
+
+/// `numerator / denominator` in lowest terms, if both parts fit in `i8`
+fn reference(numerator: i128, denominator: i128) -> Option<(i8, i8)> {
+    let gcd = reference_gcd(numerator, denominator) * denominator.signum();
+    let (numerator, denominator) = (numerator / gcd, denominator / gcd);
+    Some((
+        i8::try_from(numerator).ok()?,
+        i8::try_from(denominator).ok()?,
+    ))
+}
+
+const fn parts(value: Rational<i8>) -> (i8, i8) {
+    (value.numerator(), value.denominator().get_i8())
+}
+
+/// Whether `x` fits in `i8`
+fn fits(x: i128) -> bool {
+    i8::try_from(x).is_ok()
+}
+
+/// Every rational with an `i8` numerator and one of the `DENOMINATORS`, as `(a, b)` with `b > 0`
+fn values() -> Vec<(Rational<i8>, i128, i128)> {
+    DENOMINATORS
+        .into_iter()
+        .flat_map(|denominator| (i8::MIN..=i8::MAX).map(move |numerator| (numerator, denominator)))
+        .filter_map(|(numerator, denominator)| {
+            let value = Rational::new(numerator, NonZero::new(denominator)?)?;
+            let (a, b) = parts(value);
+            Some((value, i128::from(a), i128::from(b)))
+        })
+        .collect()
+}
+
+#[test]
+fn new_reduces_to_lowest_terms() {
+    for denominator in (i8::MIN..=i8::MAX).filter_map(NonZero::new) {
+        for numerator in i8::MIN..=i8::MAX {
+            let expected = reference(i128::from(numerator), i128::from(denominator.get_i8()));
+            assert_eq!(Rational::new(numerator, denominator).map(parts), expected);
+        }
+    }
+}
+
+#[test]
+fn every_i8_pair() {
+    let values = values();
+    for &(x, a, b) in &values {
+        for &(y, c, d) in &values {
+            assert_eq!(x.cmp(&y), (a * d).cmp(&(c * b)));
+            assert_eq!(x == y, x.cmp(&y) == Ordering::Equal);
+
+            assert_eq!(x.checked_mul(y).map(parts), reference(a * c, b * d));
+            let quotient = if c == 0 {
+                None
+            } else {
+                reference(a * d, b * c)
+            };
+            assert_eq!(x.checked_div(y).map(parts), quotient);
+
+            // Addition may also overflow on the numerators over the least common denominator
+            let gcd = reference_gcd(b, d);
+            let (left, right, denominator) = (a * (d / gcd), c * (b / gcd), b * (d / gcd));
+            let common = fits(left) && fits(right) && fits(denominator);
+            for (result, numerator) in [
+                (x.checked_add(y), left + right),
+                (x.checked_sub(y), left - right),
+            ] {
+                let expected = reference(numerator, denominator);
+                match result {
+                    Some(result) => assert_eq!(Some(parts(result)), expected),
+                    None => assert!(expected.is_none() || !common || !fits(numerator)),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn dividing_by_min_keeps_exact_results() {
+    let min = Rational::from(i8::MIN);
+    assert_eq!(min.checked_div(min), Some(Rational::from(1)));
+    let minus_half = "-1/2".parse().unwrap_or_else(|error| panic!("{error}"));
+    assert_eq!(Rational::from(64).checked_div(minus_half), Some(min));
+    assert_eq!(min.checked_div(Rational::from(-1)), None);
+    assert_eq!(min.checked_div(Rational::from(0)), None);
+    assert_eq!(Rational::from(1).checked_div(min), None);
+}
+
+#[test]
+fn parses_fractions_and_integers() {
+    let parse = |s: &str| s.parse::<Rational<i8>>().ok().map(parts);
+    assert_eq!(parse("6/-4"), Some((-3, 2)));
+    assert_eq!(parse("-128"), Some((i8::MIN, 1)));
+    assert_eq!(parse("0/-5"), Some((0, 1)));
+    assert_eq!(parse("1/0"), None);
+    assert_eq!(parse("1/-128"), None);
+    assert_eq!(parse("/2"), None);
+}
@@ -0,0 +1,37 @@
+//! `NonZero<T>` serializes as its bare value and refuses to deserialize zero
+#![cfg(feature = "serde")]
+
+use beetle_nonzero::{nonzero, NonZero};
+
+#[test]
+fn serializes_the_bare_value() {
+    assert_eq!(
+        serde_json::to_string(&nonzero!(7u32)).ok().as_deref(),
+        Some("7")
+    );
+    assert_eq!(
+        serde_json::to_string(&nonzero!(-7i8)).ok().as_deref(),
+        Some("-7")
+    );
+}
+
+#[test]
+fn deserializes_nonzero_values() {
+    assert_eq!(
+        serde_json::from_str::<NonZero<u32>>("7").ok(),
+        Some(nonzero!(7u32))
+    );
+    assert_eq!(
+        serde_json::from_str::<NonZero<i8>>("-7").ok(),
+        Some(nonzero!(-7i8))
+    );
+}
+
+#[test]
+fn rejects_zero() {
+    let error = serde_json::from_str::<NonZero<u32>>("0")
+        .err()
+        .unwrap_or_else(|| panic!("zero deserialized as a NonZero"));
+    assert!(error.to_string().contains("a nonzero value"));
+    assert!(serde_json::from_str::<NonZero<i8>>("0").is_err());
+}
@@ -0,0 +1,86 @@
+//! `Positive<T>` and `Negative<T>` keep their sign through conversions and arithmetic
+
+use beetle_nonzero::{nonzero, Negative, NonZero, Positive};
+
+fn positive(value: i8) -> Positive<i8> {
+    Positive::new(value).unwrap_or_else(|| panic!("{value} is not positive"))
+}
+
+fn negative(value: i8) -> Negative<i8> {
+    Negative::new(value).unwrap_or_else(|| panic!("{value} is not negative"))
+}
+
+#[test]
+fn converting_checks_the_sign() {
+    assert_eq!(
+        Positive::try_from(nonzero!(5i8)).map(NonZero::from),
+        Ok(nonzero!(5i8))
+    );
+    assert_eq!(
+        Negative::try_from(nonzero!(-5i8)).map(NonZero::from),
+        Ok(nonzero!(-5i8))
+    );
+
+    let error = Positive::try_from(nonzero!(-5i8))
+        .err()
+        .unwrap_or_else(|| panic!("-5 converted to Positive"));
+    assert_eq!(error.into_value(), nonzero!(-5i8));
+    let error = Negative::try_from(nonzero!(5i8))
+        .err()
+        .unwrap_or_else(|| panic!("5 converted to Negative"));
+    assert_eq!(*error.value(), nonzero!(5i8));
+
+    assert_eq!(Positive::new(0i8), None);
+    assert_eq!(Negative::new(0i8), None);
+    assert_eq!(Positive::new(-1i8), None);
+    assert_eq!(Negative::new(1i8), None);
+}
+
+#[test]
+fn multiplying_follows_the_rule_of_signs() {
+    for a in 1..=i8::MAX {
+        for b in 1..=i8::MAX {
+            let Some(product) = a.checked_mul(b) else {
+                continue;
+            };
+            assert_eq!((positive(a) * positive(b)).get().get_i8(), product);
+            assert_eq!((positive(a) * negative(-b)).get().get_i8(), -product);
+            assert_eq!((negative(-a) * positive(b)).get().get_i8(), -product);
+            assert_eq!((negative(-a) * negative(-b)).get().get_i8(), product);
+        }
+    }
+    assert_eq!(*(positive(64) * negative(-2)).get(), nonzero!(i8::MIN));
+}
+
+#[test]
+fn negating_a_positive_value_is_negative() {
+    for value in 1..=i8::MAX {
+        assert_eq!(-positive(value), negative(-value));
+    }
+}
+
+#[test]
+fn adding_keeps_the_sign() {
+    assert_eq!(positive(100) + positive(27), positive(i8::MAX));
+    assert_eq!(negative(-100) + negative(-28), negative(i8::MIN));
+    assert_eq!(positive(100).checked_add(positive(28)), None);
+    assert_eq!(negative(-100).checked_add(negative(-29)), None);
+}
+
+#[test]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn multiplying_min_by_minus_one_panics() {
+    let _ = negative(i8::MIN) * negative(-1);
+}
+
+#[test]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn multiplying_past_max_panics() {
+    let _ = positive(64) * positive(2);
+}
+
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn adding_past_min_panics() {
+    let _ = negative(-100) + negative(-29);
+}
@@ -0,0 +1,18 @@
+//! `NonZero<T>` stores primitives as the std `NonZero` types, keeping their niche
+
+use beetle_nonzero::NonZero;
+use std::mem::size_of;
+
+macro_rules! assert_sizes {
+    ($($type: ty),+) => {
+        $(
+            assert_eq!(size_of::<NonZero<$type>>(), size_of::<$type>());
+            assert_eq!(size_of::<Option<NonZero<$type>>>(), size_of::<$type>());
+        )+
+    };
+}
+
+#[test]
+fn option_of_primitive_nonzero_is_the_size_of_the_primitive() {
+    assert_sizes!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+}